@@ -0,0 +1,53 @@
+/*!
+# Fuzz: `dowser::Extension`
+
+Run with `cargo fuzz run extension` from the project root.
+*/
+
+#![no_main]
+
+use dowser::Extension;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+	let ext = Extension::slice_ext(data);
+
+	// If there's an extension, it should be a non-empty, separator-free tail
+	// of the path, preceded by a dot, preceded by something other than a
+	// separator.
+	if let Some(ext) = ext {
+		assert!(! ext.is_empty());
+		assert!(! ext.iter().any(|b| matches!(b, b'.' | b'/' | b'\\')));
+		assert!(data.ends_with(ext));
+		let dot = data.len() - ext.len() - 1;
+		assert_eq!(data[dot], b'.');
+		assert!(0 < dot && ! matches!(data[dot - 1], b'/' | b'\\'));
+	}
+
+	// The sized parsers should only match when the unsized one finds an
+	// extension of the same length.
+	let sized = [
+		Extension::slice_ext2(data),
+		Extension::slice_ext3(data),
+		Extension::slice_ext4(data),
+	];
+	for (len, e) in (2..=4).zip(sized) {
+		assert_eq!(e.is_some(), ext.is_some_and(|ext| ext.len() == len));
+	}
+
+	// And the parsed value should match a reference built from the
+	// (lowercased) bytes.
+	let expected = ext.and_then(|ext| match *ext {
+		[a, b] => Some(Extension::new2([a, b].map(|b| b.to_ascii_lowercase()))),
+		[a, b, c] => Some(Extension::new3([a, b, c].map(|b| b.to_ascii_lowercase()))),
+		[a, b, c, d] => Some(Extension::new4([a, b, c, d].map(|b| b.to_ascii_lowercase()))),
+		_ => None,
+	});
+	assert_eq!(sized.into_iter().flatten().next(), expected);
+
+	// The plural iterator should start with the same thing, and never run
+	// longer than there are dots.
+	let mut iter = Extension::slice_exts(data);
+	assert_eq!(iter.next(), expected);
+	assert!(iter.count() <= data.iter().filter(|b| b'.'.eq(b)).count());
+});
@@ -0,0 +1,273 @@
+/*!
+# Dowser: Archives
+*/
+
+use crate::Extension;
+use std::{
+	ffi::OsString,
+	fs::File,
+	ops::BitOr,
+	path::{
+		Component,
+		Path,
+		PathBuf,
+	},
+};
+
+
+
+/// # Tar Extension.
+const EXT_TAR: Extension = Extension::new3(*b"tar");
+
+/// # Zip Extension.
+const EXT_ZIP: Extension = Extension::new3(*b"zip");
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Archive Kinds.
+///
+/// This is a simple flag set of the archive formats [`Dowser`](crate::Dowser)
+/// should crawl _into_ when [`Dowser::with_archives`](crate::Dowser::with_archives)
+/// is enabled.
+///
+/// Archives are identified by file extension — `.tar` or `.zip` — and their
+/// (regular file) members are yielded as virtual paths in place of the
+/// archive itself, like `/data/site.zip!/index.html`.
+///
+/// ## Examples
+///
+/// ```
+/// use dowser::ArchiveKinds;
+///
+/// let kinds = ArchiveKinds::TAR | ArchiveKinds::ZIP;
+/// assert_eq!(kinds, ArchiveKinds::ALL);
+/// assert!(kinds.contains(ArchiveKinds::TAR));
+/// assert!(! ArchiveKinds::NONE.contains(ArchiveKinds::ZIP));
+/// ```
+pub struct ArchiveKinds(u8);
+
+impl BitOr for ArchiveKinds {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self::Output { Self(self.0 | rhs.0) }
+}
+
+impl ArchiveKinds {
+	/// # None.
+	pub const NONE: Self = Self(0b0000);
+
+	/// # Tar (`.tar`).
+	pub const TAR: Self = Self(0b0001);
+
+	/// # Zip (`.zip`).
+	pub const ZIP: Self = Self(0b0010);
+
+	/// # All Supported Formats.
+	pub const ALL: Self = Self(0b0011);
+}
+
+impl ArchiveKinds {
+	#[must_use]
+	#[inline]
+	/// # Contains?
+	///
+	/// Returns `true` if all of the kinds in `other` are enabled in `self`.
+	pub const fn contains(self, other: Self) -> bool {
+		other.0 != 0 && self.0 & other.0 == other.0
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Is Empty?
+	///
+	/// Returns `true` if no kinds are enabled.
+	pub const fn is_empty(self) -> bool { self.0 == 0 }
+
+	/// # Archive Members.
+	///
+	/// If `src` is an enabled archive kind, return the virtual paths of the
+	/// regular files it contains.
+	///
+	/// This returns `None` if `src` is not an (enabled) archive, or if it
+	/// cannot be parsed as one; either way, the caller should treat it as
+	/// a regular old file.
+	pub(crate) fn members(self, src: &Path) -> Option<Vec<PathBuf>> {
+		if self.is_empty() { None }
+		else if self.contains(Self::TAR) && EXT_TAR == src { tar_members(src) }
+		else if self.contains(Self::ZIP) && EXT_ZIP == src { zip_members(src) }
+		else { None }
+	}
+}
+
+
+
+/// # Tar Members.
+///
+/// Return the virtual paths for each regular file in a tarball.
+fn tar_members(src: &Path) -> Option<Vec<PathBuf>> {
+	let file = File::open(src).ok()?;
+	let mut archive = tar::Archive::new(file);
+	let mut out = Vec::new();
+	for e in archive.entries_with_seek().ok()? {
+		// Skip unreadable entries rather than giving up on the whole thing.
+		let Ok(e) = e else { continue; };
+		if e.header().entry_type().is_file() {
+			if let Some(p) = e.path().ok().and_then(|m| virtual_path(src, &m)) {
+				out.push(p);
+			}
+		}
+	}
+
+	Some(out)
+}
+
+/// # Zip Members.
+///
+/// Return the virtual paths for each regular file in a zip.
+fn zip_members(src: &Path) -> Option<Vec<PathBuf>> {
+	let file = File::open(src).ok()?;
+	let archive = zip::ZipArchive::new(file).ok()?;
+	Some(
+		archive.file_names()
+			.filter(|n| ! n.ends_with('/'))
+			.filter_map(|n| virtual_path(src, Path::new(n)))
+			.collect()
+	)
+}
+
+/// # Virtual Path.
+///
+/// Glue an archive path and member name together, like `archive!/member`.
+///
+/// To keep members from escaping their archive — or colliding with one
+/// another once normalized — this returns `None` if the member is absolute,
+/// or contains any empty, `.`, or `..` segments. (A single leading `./`, as
+/// commonly found in tarballs, is allowed, and dropped.)
+fn virtual_path(src: &Path, member: &Path) -> Option<PathBuf> {
+	let raw = member.as_os_str().as_encoded_bytes();
+	let raw = raw.strip_prefix(b"./").unwrap_or(raw);
+	if raw.split(|b| b'/'.eq(b)).any(|seg| matches!(seg, b"" | b"." | b"..")) {
+		return None;
+	}
+
+	let mut out = OsString::from(src.as_os_str());
+	out.push("!");
+	for c in member.components() {
+		match c {
+			Component::Normal(c) => {
+				out.push("/");
+				out.push(c);
+			},
+			Component::CurDir => {},
+			_ => return None,
+		}
+	}
+
+	Some(PathBuf::from(out))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_virtual_path() {
+		let src = Path::new("/data/site.zip");
+		for (member, expected) in [
+			("index.html", "/data/site.zip!/index.html"),
+			("./index.html", "/data/site.zip!/index.html"),
+			("css/main.css", "/data/site.zip!/css/main.css"),
+		] {
+			assert_eq!(
+				virtual_path(src, Path::new(member)).as_deref(),
+				Some(Path::new(expected)),
+			);
+		}
+
+		// Anything that would need normalizing is rejected.
+		for member in [
+			"/index.html",
+			"../index.html",
+			"css/../index.html",
+			"css/./main.css",
+			"css//main.css",
+			"././index.html",
+			".",
+			"",
+		] {
+			assert!(virtual_path(src, Path::new(member)).is_none(), "{member:?} should be rejected.");
+		}
+	}
+
+	#[test]
+	fn t_members() {
+		use std::io::Write;
+
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		// Build a tarball with a couple files and a directory.
+		let tar_file = tmp.join("dowser.test.tar");
+		let res = File::create(&tar_file).and_then(|file| {
+			let mut builder = tar::Builder::new(file);
+			for (name, data) in [("a.txt", &b"Hello"[..]), ("sub/b.txt", &b"World"[..])] {
+				let mut header = tar::Header::new_gnu();
+				header.set_size(data.len() as u64);
+				header.set_mode(0o644);
+				header.set_cksum();
+				builder.append_data(&mut header, name, data)?;
+			}
+			let mut header = tar::Header::new_gnu();
+			header.set_entry_type(tar::EntryType::Directory);
+			header.set_size(0);
+			header.set_cksum();
+			builder.append_data(&mut header, "sub/", std::io::empty())?;
+			builder.into_inner()?.flush()
+		});
+
+		// Build a zip with the same.
+		let zip_file = tmp.join("dowser.test.zip");
+		let res2 = File::create(&zip_file).and_then(|file| {
+			let mut zip = zip::ZipWriter::new(file);
+			let opts = zip::write::SimpleFileOptions::default()
+				.compression_method(zip::CompressionMethod::Stored);
+			zip.add_directory("sub/", opts)?;
+			for (name, data) in [("a.txt", &b"Hello"[..]), ("sub/b.txt", &b"World"[..])] {
+				zip.start_file(name, opts)?;
+				zip.write_all(data)?;
+			}
+			zip.finish()?.flush()
+		});
+
+		if res.is_ok() && res2.is_ok() {
+			let mut found = ArchiveKinds::ALL.members(&tar_file)
+				.expect("Failed to read tar members.");
+			found.sort();
+			assert_eq!(found, [
+				virtual_path(&tar_file, Path::new("a.txt")).expect("Bad member."),
+				virtual_path(&tar_file, Path::new("sub/b.txt")).expect("Bad member."),
+			]);
+
+			let mut found = ArchiveKinds::ALL.members(&zip_file)
+				.expect("Failed to read zip members.");
+			found.sort();
+			assert_eq!(found, [
+				virtual_path(&zip_file, Path::new("a.txt")).expect("Bad member."),
+				virtual_path(&zip_file, Path::new("sub/b.txt")).expect("Bad member."),
+			]);
+
+			// Disabled kinds should be ignored.
+			assert!(ArchiveKinds::ZIP.members(&tar_file).is_none());
+			assert!(ArchiveKinds::TAR.members(&zip_file).is_none());
+			assert!(ArchiveKinds::NONE.members(&zip_file).is_none());
+		}
+
+		let _res = std::fs::remove_file(tar_file);
+		let _res = std::fs::remove_file(zip_file);
+	}
+}
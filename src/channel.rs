@@ -0,0 +1,280 @@
+/*!
+# Dowser: Crawl Channels
+*/
+
+use crate::{
+	Dowser,
+	Visitor,
+};
+use std::{
+	ops::ControlFlow,
+	path::{
+		Path,
+		PathBuf,
+	},
+	sync::{
+		Arc,
+		Condvar,
+		Mutex,
+		MutexGuard,
+		mpsc::{
+			self,
+			Receiver,
+			Sender,
+		},
+		PoisonError,
+	},
+	thread::JoinHandle,
+};
+
+
+
+impl Dowser {
+	#[must_use]
+	/// # Crawl Channel.
+	///
+	/// Crawl the queued paths in a background thread, returning a
+	/// [`CrawlHandle`] for controlling it, and a [`Receiver`] for the files
+	/// found.
+	///
+	/// The crawl can be paused, resumed, or cancelled through the handle at
+	/// any time — taking effect before the next file is sent or directory
+	/// read — and will also stop on its own if the receiver is dropped.
+	///
+	/// This is mainly intended for interactive applications — file pickers
+	/// and the like — that need to keep an eye on long-running crawls
+	/// without blocking.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let (handle, rx) = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .crawl_channel();
+	///
+	/// for (idx, path) in rx.iter().enumerate() {
+	///     println!("{}", path.display());
+	///
+	///     // That's enough!
+	///     if idx == 99 { handle.cancel(); }
+	/// }
+	/// ```
+	pub fn crawl_channel(self) -> (CrawlHandle, Receiver<PathBuf>) {
+		self.spawn_channel(Arc::new(Control::default()))
+	}
+
+	/// # Spawn Channel.
+	///
+	/// Start the background crawl for [`Dowser::crawl_channel`] using the
+	/// given control state.
+	fn spawn_channel(self, control: Arc<Control>) -> (CrawlHandle, Receiver<PathBuf>) {
+		let (tx, rx) = mpsc::channel();
+		let mut sender = ChannelVisitor { tx, control: Arc::clone(&control) };
+		let thread = std::thread::spawn(move || self.walk(&mut sender));
+		(CrawlHandle { control, thread: Some(thread) }, rx)
+	}
+}
+
+
+
+/// # Channel Visitor.
+///
+/// This checks in with the control state before each file is sent and each
+/// directory is read, blocking while paused.
+struct ChannelVisitor {
+	/// # Sender.
+	tx: Sender<PathBuf>,
+
+	/// # Shared State.
+	control: Arc<Control>,
+}
+
+impl Visitor for ChannelVisitor {
+	fn visit_file(&mut self, path: &Path) -> ControlFlow<()> {
+		// Stop if we've been cancelled or the receiver is gone.
+		if self.control.wait() && self.tx.send(path.to_path_buf()).is_ok() {
+			ControlFlow::Continue(())
+		}
+		else { ControlFlow::Break(()) }
+	}
+
+	fn visit_dir(&mut self, _path: &Path) -> bool { self.control.wait() }
+}
+
+
+
+#[derive(Debug)]
+/// # Crawl Handle.
+///
+/// This is used to control a background crawl started by
+/// [`Dowser::crawl_channel`].
+///
+/// Dropping the handle resumes a paused crawl — so it can't get stuck — but
+/// otherwise leaves it running.
+pub struct CrawlHandle {
+	/// # Shared State.
+	control: Arc<Control>,
+
+	/// # Crawl Thread.
+	thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for CrawlHandle {
+	fn drop(&mut self) { self.resume(); }
+}
+
+impl CrawlHandle {
+	/// # Cancel.
+	///
+	/// Stop the crawl at the next opportunity. Any paths already sent will
+	/// remain in the channel.
+	pub fn cancel(&self) { self.control.set(State::Cancelled); }
+
+	/// # Pause.
+	///
+	/// Pause the crawl (at the next opportunity) until [`CrawlHandle::resume`]
+	/// is called.
+	pub fn pause(&self) { self.control.set(State::Paused); }
+
+	/// # Resume.
+	///
+	/// Resume a paused crawl. This has no effect on a cancelled one.
+	pub fn resume(&self) { self.control.set(State::Running); }
+
+	#[must_use]
+	/// # Is Cancelled?
+	pub fn is_cancelled(&self) -> bool { matches!(*self.control.lock(), State::Cancelled) }
+
+	#[must_use]
+	/// # Is Paused?
+	pub fn is_paused(&self) -> bool { matches!(*self.control.lock(), State::Paused) }
+
+	#[must_use]
+	/// # Is Finished?
+	///
+	/// Returns `true` once the background thread has exited, whether because
+	/// the crawl ran its course, was cancelled, or the receiver was dropped.
+	pub fn is_finished(&self) -> bool {
+		self.thread.as_ref().is_none_or(JoinHandle::is_finished)
+	}
+
+	/// # Join.
+	///
+	/// Block until the background thread has exited.
+	///
+	/// Note: a paused crawl is resumed first to avoid waiting forever.
+	pub fn join(mut self) {
+		self.resume();
+		if let Some(thread) = self.thread.take() { let _res = thread.join(); }
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Crawl State.
+enum State {
+	#[default]
+	/// # Running.
+	Running,
+
+	/// # Paused.
+	Paused,
+
+	/// # Cancelled.
+	Cancelled,
+}
+
+#[derive(Debug, Default)]
+/// # Crawl Control.
+///
+/// The state shared between a [`CrawlHandle`] and its crawl thread.
+struct Control {
+	/// # State.
+	state: Mutex<State>,
+
+	/// # Wakeup Signal.
+	cvar: Condvar,
+}
+
+impl Control {
+	/// # Lock.
+	fn lock(&self) -> MutexGuard<'_, State> {
+		self.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// # Set State.
+	///
+	/// Change the state, waking up the crawl if it was paused. Cancellation
+	/// is permanent.
+	fn set(&self, new: State) {
+		let mut state = self.lock();
+		if *state != State::Cancelled {
+			*state = new;
+			drop(state);
+			self.cvar.notify_all();
+		}
+	}
+
+	/// # Wait.
+	///
+	/// Block while paused, then return `true` to keep going, or `false` if
+	/// cancelled.
+	fn wait(&self) -> bool {
+		let state = self.cvar
+			.wait_while(self.lock(), |s| *s == State::Paused)
+			.unwrap_or_else(PoisonError::into_inner);
+		*state == State::Running
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_crawl_channel() {
+		// Everything.
+		let (handle, rx) = Dowser::from("tests/").crawl_channel();
+		let mut found: Vec<PathBuf> = rx.iter().collect();
+		found.sort();
+		assert_eq!(found, Dowser::from("tests/").into_sorted_vec());
+		assert!(! handle.is_cancelled());
+		handle.join();
+
+		// Paused, then resumed.
+		let (tx, rx) = mpsc::channel();
+		let control = Arc::new(Control::default());
+		control.set(State::Paused);
+		assert!(*control.lock() == State::Paused);
+		let control2 = Arc::clone(&control);
+		let thread = std::thread::spawn(move || { tx.send(control2.wait()).ok(); });
+		assert!(rx.recv_timeout(std::time::Duration::from_millis(50)).is_err());
+		control.set(State::Running);
+		assert_eq!(rx.recv().ok(), Some(true));
+		assert!(thread.join().is_ok());
+
+		// Paused before it starts, then cancelled: nothing should get through.
+		let control = Arc::new(Control::default());
+		control.set(State::Paused);
+		let (handle, rx) = Dowser::from("tests/").spawn_channel(control);
+		assert!(handle.is_paused());
+		assert!(rx.recv_timeout(std::time::Duration::from_millis(50)).is_err());
+		assert!(! handle.is_finished());
+		handle.cancel();
+		handle.resume();
+		assert!(handle.is_cancelled());
+		assert!(! handle.is_paused());
+		handle.join();
+		assert_eq!(rx.iter().count(), 0);
+
+		// Dropping the receiver stops the crawl too.
+		let (handle, rx) = Dowser::from("tests/").crawl_channel();
+		drop(rx);
+		handle.join();
+	}
+}
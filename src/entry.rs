@@ -4,11 +4,16 @@
 
 use std::{
 	fs::DirEntry,
-	io::Result,
+	io::{
+		Error,
+		ErrorKind,
+		Result,
+	},
 	path::{
 		Path,
 		PathBuf,
 	},
+	time::Duration,
 };
 
 
@@ -33,6 +38,12 @@ pub(super) struct Entry {
 	/// # Path.
 	pub(super) path: PathBuf,
 
+	/// # Symlink Path.
+	///
+	/// If this entry was reached by following a symlink, this holds the
+	/// (uncanonicalized) path to the link itself.
+	pub(super) link: Option<PathBuf>,
+
 	/// # Is Directory?
 	pub(super) is_dir: bool,
 
@@ -41,25 +52,53 @@ pub(super) struct Entry {
 }
 
 impl Entry {
-	#[must_use]
 	/// # From Entry (Result).
 	///
 	/// Because [`Dowser`] canonicalizes all seed paths, we can assume that
 	/// any non-symlinked `DirEntry` is also canonical, thus avoiding expensive
 	/// syscalls. (If it is, we'll canonicalize it first.)
-	pub(super) fn from_entry(e: Result<DirEntry>) -> Option<Self> {
-		// If this is a symlink, we have to follow it.
-		let e = e.ok()?;
-		let ft = e.file_type().ok()?;
-		if ft.is_symlink() { Self::from_path(e.path()) }
+	///
+	/// If `follow` is false, symlinks are skipped entirely. Otherwise they
+	/// are canonicalized according to the `retry` policy, provided they
+	/// don't chain through more than `max_hops` links, if specified.
+	///
+	/// ## Errors
+	///
+	/// If a followed symlink cannot be resolved — i.e. it is broken, or has
+	/// too many hops — the link path and error are returned so the caller
+	/// can report them. All other failures are simply `Ok(None)`.
+	pub(super) fn from_entry(
+		e: Result<DirEntry>,
+		follow: bool,
+		retry: Retry,
+		max_hops: Option<u8>,
+	) -> std::result::Result<Option<Self>, (PathBuf, Error)> {
+		// If this is a symlink, we have to follow it (or not).
+		let Ok(e) = e else { return Ok(None); };
+		let Ok(ft) = e.file_type() else { return Ok(None); };
+		if ft.is_symlink() {
+			if follow {
+				let link = e.path();
+				if let Some(max) = max_hops {
+					if let Err(err) = check_hops(&link, max) { return Err((link, err)); }
+				}
+
+				match retry.run(|| std::fs::canonicalize(&link)) {
+					Ok(path) => {
+						let is_dir = path.is_dir();
+						let hash = Self::hash_path(&path, is_dir);
+						Ok(Some(Self { path, link: Some(link), is_dir, hash }))
+					},
+					Err(err) => Err((link, err)),
+				}
+			}
+			else { Ok(None) }
+		}
 		else {
 			let path = e.path();
-			let hash = Self::hash_path(&path);
-			Some(Self {
-				path,
-				is_dir: ft.is_dir(),
-				hash,
-			})
+			let is_dir = ft.is_dir();
+			let hash = Self::hash_path(&path, is_dir);
+			Ok(Some(Self { path, link: None, is_dir, hash }))
 		}
 	}
 
@@ -71,10 +110,10 @@ impl Entry {
 	pub(super) fn from_path<P>(path: P) -> Option<Self>
 	where P: AsRef<Path> {
 		let path = std::fs::canonicalize(path).ok()?;
-		let hash = Self::hash_path(&path);
 		let is_dir = path.is_dir();
+		let hash = Self::hash_path(&path, is_dir);
 
-		Some(Self { path, is_dir, hash })
+		Some(Self { path, link: None, is_dir, hash })
 	}
 
 	#[cfg(unix)]
@@ -84,9 +123,12 @@ impl Entry {
 	///
 	/// Since all paths are canonical, we can test for uniqueness by simply
 	/// hashing them.
-	pub(super) fn hash_path(path: &Path) -> u64 {
+	///
+	/// The type is mixed in too so that file and directory hashes occupy
+	/// separate namespaces and can never suppress one another.
+	pub(super) fn hash_path(path: &Path, is_dir: bool) -> u64 {
 		use std::os::unix::ffi::OsStrExt;
-		AHASHER.hash_one(path.as_os_str().as_bytes())
+		AHASHER.hash_one((is_dir, path.as_os_str().as_bytes()))
 	}
 
 	#[cfg(not(unix))]
@@ -96,5 +138,181 @@ impl Entry {
 	///
 	/// Since all paths are canonical, we can test for uniqueness by simply
 	/// hashing them.
-	pub(super) fn hash_path(path: &Path) -> u64 { AHASHER.hash_one(path) }
+	///
+	/// The type is mixed in too so that file and directory hashes occupy
+	/// separate namespaces and can never suppress one another.
+	pub(super) fn hash_path(path: &Path, is_dir: bool) -> u64 {
+		AHASHER.hash_one((is_dir, path))
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Retry Policy.
+///
+/// This holds the number of extra attempts to make — and the delay between
+/// them — when a filesystem operation fails for (probably) transient
+/// reasons.
+pub(super) struct Retry {
+	/// # Attempts.
+	attempts: u8,
+
+	/// # Backoff.
+	backoff: Duration,
+}
+
+impl Retry {
+	/// # None.
+	///
+	/// Never retry anything.
+	pub(super) const NONE: Self = Self { attempts: 0, backoff: Duration::ZERO };
+
+	#[must_use]
+	/// # New.
+	pub(super) const fn new(attempts: u8, backoff: Duration) -> Self {
+		Self { attempts, backoff }
+	}
+
+	#[must_use]
+	/// # Is Enabled?
+	pub(super) const fn is_enabled(self) -> bool { 0 != self.attempts }
+
+	/// # Run.
+	///
+	/// Run the callback, running it again — up to the configured number of
+	/// times — if it fails with a transient error.
+	///
+	/// ## Errors
+	///
+	/// The last result is returned as-is, error or not.
+	pub(super) fn run<T, F>(self, mut cb: F) -> Result<T>
+	where F: FnMut() -> Result<T> {
+		let mut res = cb();
+		for _ in 0..self.attempts {
+			match &res {
+				Err(e) if is_transient(e) => {
+					if ! self.backoff.is_zero() { std::thread::sleep(self.backoff); }
+					res = cb();
+				},
+				_ => break,
+			}
+		}
+		res
+	}
+}
+
+
+
+/// # Check Symlink Hops.
+///
+/// Manually walk the chain of symlinks starting at `link` — following the
+/// final path component only — and make sure there are no more than `max`
+/// of them, counting `link` itself.
+///
+/// ## Errors
+///
+/// Returns an error if the chain is too long.
+fn check_hops(link: &Path, max: u8) -> Result<()> {
+	let mut cur = link.to_path_buf();
+	let mut hops = 0_u8;
+	while let Ok(target) = std::fs::read_link(&cur) {
+		if hops == max {
+			return Err(Error::other(format!("symlink chain exceeds {max} hop(s)")));
+		}
+		hops += 1;
+
+		cur = match cur.parent() {
+			Some(parent) if target.is_relative() => parent.join(target),
+			_ => target,
+		};
+	}
+
+	Ok(())
+}
+
+#[must_use]
+/// # Is Transient Error?
+///
+/// Returns `true` for the kinds of I/O errors that might go away on their
+/// own: interruptions, timeouts, busy or stale handles, and (on Unix)
+/// generic `EIO` failures.
+pub(super) fn is_transient(e: &Error) -> bool {
+	/// # Raw `EIO`.
+	#[cfg(unix)]
+	const EIO: i32 = 5;
+
+	#[cfg(unix)]
+	if e.raw_os_error() == Some(EIO) { return true; }
+
+	matches!(
+		e.kind(),
+		ErrorKind::Interrupted |
+		ErrorKind::ResourceBusy |
+		ErrorKind::StaleNetworkFileHandle |
+		ErrorKind::TimedOut |
+		ErrorKind::WouldBlock
+	)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(unix)]
+	#[test]
+	fn t_check_hops() {
+		let links = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser test directory.");
+
+		// Not a link at all.
+		assert!(check_hops(&links.join("01"), 0).is_ok());
+
+		// 06/10 -> 01.
+		assert!(check_hops(&links.join("06/10"), 0).is_err());
+		assert!(check_hops(&links.join("06/10"), 1).is_ok());
+	}
+
+	#[test]
+	fn t_retry() {
+		// Transient errors are retried.
+		let mut calls = 0;
+		let res: Result<()> = Retry::new(3, Duration::ZERO).run(|| {
+			calls += 1;
+			Err(Error::from(ErrorKind::TimedOut))
+		});
+		assert!(res.is_err());
+		assert_eq!(calls, 4);
+
+		// Until they work.
+		let mut calls = 0;
+		let res = Retry::new(3, Duration::ZERO).run(|| {
+			calls += 1;
+			if calls < 2 { Err(Error::from(ErrorKind::Interrupted)) }
+			else { Ok(calls) }
+		});
+		assert_eq!(res.ok(), Some(2));
+
+		// Other errors are not.
+		for kind in [ErrorKind::NotFound, ErrorKind::PermissionDenied] {
+			let mut calls = 0;
+			let res: Result<()> = Retry::new(3, Duration::ZERO).run(|| {
+				calls += 1;
+				Err(Error::from(kind))
+			});
+			assert!(res.is_err());
+			assert_eq!(calls, 1, "{kind:?} should not be retried.");
+		}
+
+		// And nothing is retried without attempts.
+		let mut calls = 0;
+		let res: Result<()> = Retry::NONE.run(|| {
+			calls += 1;
+			Err(Error::from(ErrorKind::TimedOut))
+		});
+		assert!(res.is_err());
+		assert_eq!(calls, 1);
+	}
 }
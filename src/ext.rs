@@ -2,16 +2,27 @@
 # Dowser: Extension
 */
 
+pub mod common;
+mod map;
+
+pub use map::ExtensionMap;
+
+use common::Category;
+
 use dactyl::{
 	NiceU16,
 	NiceU32,
 };
 use std::{
+	ffi::OsStr,
 	hash::{
 		Hash,
 		Hasher,
 	},
-	path::Path,
+	path::{
+		Component,
+		Path,
+	},
 };
 
 #[cfg(unix)]
@@ -38,7 +49,16 @@ macro_rules! path_slice {
 
 
 
-#[derive(Debug, Clone, Copy)]
+/// # Extension Byte.
+///
+/// Match any byte but a period or forward/backward slash.
+macro_rules! ext_byte {
+	() => (0..=45 | 48..=91 | 93..=255);
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 /// # Extension.
 ///
 /// This enum can be used to efficiently check a file path's extension case-
@@ -131,6 +151,24 @@ macro_rules! path_slice {
 ///     .filter(|p| Extension::try_from3(p) == Some(EXT))
 ///     .for_each(|p| todo!());
 /// ```
+///
+/// ## Pattern Matching
+///
+/// Reference constants can also be used directly as `match` patterns,
+/// making it easy to classify a runtime value against several at once:
+///
+/// ```
+/// use dowser::Extension;
+///
+/// const JPG: Extension = Extension::new3(*b"jpg");
+/// const PNG: Extension = Extension::new3(*b"png");
+///
+/// let ext = Extension::try_from3("/path/to/IMAGE.PNG");
+/// assert!(matches!(ext, Some(JPG | PNG)));
+/// ```
+///
+/// For `const` contexts, where `==` is unavailable, see
+/// [`Extension::eq_const`].
 pub enum Extension {
 	/// # 2-char Extension.
 	///
@@ -148,8 +186,6 @@ pub enum Extension {
 	Ext4(u32),
 }
 
-impl Eq for Extension {}
-
 impl Hash for Extension {
 	#[inline]
 	fn hash<H: Hasher>(&self, state: &mut H) {
@@ -160,18 +196,6 @@ impl Hash for Extension {
 	}
 }
 
-impl PartialEq for Extension {
-	#[inline]
-	fn eq(&self, other: &Self) -> bool {
-		match (*self, *other) {
-			(Self::Ext2(e1), Self::Ext2(e2)) => e1 == e2,
-			(Self::Ext3(e1), Self::Ext3(e2)) |
-			(Self::Ext4(e1), Self::Ext4(e2)) => e1 == e2,
-			_ => false,
-		}
-	}
-}
-
 impl<P> PartialEq<P> for Extension
 where P: AsRef<Path> {
 	#[inline]
@@ -272,6 +296,110 @@ impl Extension {
 	}
 }
 
+/// # Packing.
+impl Extension {
+	#[must_use]
+	/// # As `u32`.
+	///
+	/// Pack the extension into a single `u32`, handy for keying compact
+	/// indexes without the enum's extra padding.
+	///
+	/// The packing is lossless and unique — the different lengths can't be
+	/// confused for one another — so the value can be turned back into an
+	/// `Extension` with [`Extension::from_u32`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// const EXT: Extension = Extension::new3(*b"jpg");
+	/// assert_eq!(Extension::from_u32(EXT.as_u32()), Some(EXT));
+	/// ```
+	pub const fn as_u32(self) -> u32 {
+		match self {
+			Self::Ext2(n) => n as u32,
+			Self::Ext3(n) | Self::Ext4(n) => n,
+		}
+	}
+
+	#[must_use]
+	/// # From `u32`.
+	///
+	/// Unpack an extension previously packed by [`Extension::as_u32`].
+	///
+	/// This returns `None` if the value could not have come from a
+	/// (sane) extension, i.e. one containing periods, slashes, or `NUL`
+	/// bytes.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// const EXT: Extension = Extension::new2(*b"gz");
+	/// assert_eq!(Extension::from_u32(EXT.as_u32()), Some(EXT));
+	///
+	/// assert_eq!(Extension::from_u32(0), None);
+	/// ```
+	pub const fn from_u32(packed: u32) -> Option<Self> {
+		/// # Valid Byte?
+		const fn valid(b: u8) -> bool { b != 0 && matches!(b, ext_byte!()) }
+
+		match packed.to_le_bytes() {
+			[b'.', a, b, c] if valid(a) && valid(b) && valid(c) => Some(Self::Ext3(packed)),
+			[a, b, 0, 0] if valid(a) && valid(b) => Some(Self::Ext2(u16::from_le_bytes([a, b]))),
+			[a, b, c, d] if valid(a) && valid(b) && valid(c) && valid(d) => Some(Self::Ext4(packed)),
+			_ => None,
+		}
+	}
+}
+
+/// # Parsing.
+impl Extension {
+	#[must_use]
+	/// # Parse (Leniently).
+	///
+	/// Parse an extension from a user-supplied string, such as a CLI
+	/// argument. Unlike the unchecked constructors, the value is validated,
+	/// and casing is fixed automatically.
+	///
+	/// A single leading period is also allowed, so `"jpg"` and `".jpg"`
+	/// parse the same.
+	///
+	/// This returns `None` if the remainder is not 2-4 bytes, or contains
+	/// any (more) periods, whitespace, or path separators.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// const MY_EXT: Extension = Extension::new3(*b"jpg");
+	/// assert_eq!(Extension::parse_lenient("jpg"), Some(MY_EXT));
+	/// assert_eq!(Extension::parse_lenient(".jpg"), Some(MY_EXT));
+	/// assert_eq!(Extension::parse_lenient(".JPG"), Some(MY_EXT));
+	///
+	/// assert_eq!(Extension::parse_lenient("..jpg"), None);
+	/// assert_eq!(Extension::parse_lenient("j.pg"), None);
+	/// assert_eq!(Extension::parse_lenient("jpg "), None);
+	/// assert_eq!(Extension::parse_lenient("jpegs"), None);
+	/// ```
+	pub fn parse_lenient(src: &str) -> Option<Self> {
+		let src = src.strip_prefix('.').unwrap_or(src).as_bytes();
+		if src.iter().any(|b| b.is_ascii_whitespace() || matches!(b, b'.' | b'/' | b'\\')) {
+			return None;
+		}
+
+		match *src {
+			[a, b] => Some(Self::new2([a, b].map(|b| b.to_ascii_lowercase()))),
+			[a, b, c] => Some(Self::new3([a, b, c].map(|b| b.to_ascii_lowercase()))),
+			[a, b, c, d] => Some(Self::new4([a, b, c, d].map(|b| b.to_ascii_lowercase()))),
+			_ => None,
+		}
+	}
+}
+
 /// # From Paths.
 impl Extension {
 	#[must_use]
@@ -371,7 +499,8 @@ impl Extension {
 	/// This method is used to (try to) pull a 2-byte extension from a file
 	/// path in slice form. This requires that the path be at least 4 bytes,
 	/// with anything but a forward/backward slash at `[len - 4]` and a dot at
-	/// `[len - 3]`.
+	/// `[len - 3]`. The extension bytes themselves may not be dots or
+	/// slashes.
 	///
 	/// If successful, it will return an [`Extension::Ext2`] that can be
 	/// compared against your reference [`Extension`]. Casing will be fixed
@@ -389,11 +518,15 @@ impl Extension {
 	/// // Non-matches.
 	/// assert_eq!(Extension::slice_ext2(b"/path/to/.gz"), None);
 	/// assert_eq!(Extension::slice_ext2(b"/path/to\\.gz"), None);
+	/// assert_eq!(Extension::slice_ext2(b"/path/to.g/"), None);
 	/// assert_eq!(Extension::slice_ext2(b"/path/to/file.png"), None);
 	/// assert_ne!(Extension::slice_ext2(b"/path/to/file.br"), Some(MY_EXT));
 	/// ```
 	pub const fn slice_ext2(path: &[u8]) -> Option<Self> {
-		if let [.., 0..=46 | 48..=91 | 93..=255, b'.', a, b] = path {
+		if let [
+			.., 0..=46 | 48..=91 | 93..=255, b'.',
+			a @ ext_byte!(), b @ ext_byte!(),
+		] = path {
 			Some(Self::Ext2(u16::from_le_bytes([
 				a.to_ascii_lowercase(),
 				b.to_ascii_lowercase(),
@@ -409,7 +542,8 @@ impl Extension {
 	/// This method is used to (try to) pull a 3-byte extension from a file
 	/// path in slice form. This requires that the path be at least 5 bytes,
 	/// with anything but a forward/backward slash at `[len - 5]` and a dot at
-	/// `[len - 4]`.
+	/// `[len - 4]`. The extension bytes themselves may not be dots or
+	/// slashes.
 	///
 	/// If successful, it will return an [`Extension::Ext3`] that can be
 	/// compared against your reference [`Extension`]. Casing will be fixed
@@ -427,11 +561,15 @@ impl Extension {
 	/// // Non-matches.
 	/// assert_eq!(Extension::slice_ext3(b"/path/to/.png"), None);
 	/// assert_eq!(Extension::slice_ext3(b"/path/to\\.png"), None);
+	/// assert_eq!(Extension::slice_ext3(b"/path/to/file.p.g"), None);
 	/// assert_eq!(Extension::slice_ext3(b"/path/to/file.html"), None);
 	/// assert_ne!(Extension::slice_ext3(b"/path/to/file.jpg"), Some(MY_EXT));
 	/// ```
 	pub const fn slice_ext3(path: &[u8]) -> Option<Self> {
-		if let [.., 0..=46 | 48..=91 | 93..=255, b'.', a, b, c] = path {
+		if let [
+			.., 0..=46 | 48..=91 | 93..=255, b'.',
+			a @ ext_byte!(), b @ ext_byte!(), c @ ext_byte!(),
+		] = path {
 			Some(Self::Ext3(u32::from_le_bytes([
 				b'.',
 				a.to_ascii_lowercase(),
@@ -449,7 +587,8 @@ impl Extension {
 	/// This method is used to (try to) pull a 4-byte extension from a file
 	/// path in slice form. This requires that the path be at least 6 bytes,
 	/// with anything but a forward/backward slash at `[len - 6]` and a dot at
-	/// `[len - 5]`.
+	/// `[len - 5]`. The extension bytes themselves may not be dots or
+	/// slashes.
 	///
 	/// If successful, it will return an [`Extension::Ext4`] that can be
 	/// compared against your reference [`Extension`]. Casing will be fixed
@@ -467,11 +606,15 @@ impl Extension {
 	/// // Non-matches.
 	/// assert_eq!(Extension::slice_ext2(b"/path/to/.html"), None);
 	/// assert_eq!(Extension::slice_ext2(b"/path/to\\.html"), None);
+	/// assert_eq!(Extension::slice_ext4(b"/path/to/file.h.ml"), None);
 	/// assert_eq!(Extension::slice_ext4(b"/path/to/file.png"), None);
 	/// assert_ne!(Extension::slice_ext4(b"/path/to/file.xhtm"), Some(MY_EXT));
 	/// ```
 	pub const fn slice_ext4(path: &[u8]) -> Option<Self> {
-		if let [.., 0..=46 | 48..=91 | 93..=255, b'.', a, b, c, d] = path {
+		if let [
+			.., 0..=46 | 48..=91 | 93..=255, b'.',
+			a @ ext_byte!(), b @ ext_byte!(), c @ ext_byte!(), d @ ext_byte!(),
+		] = path {
 			Some(Self::Ext4(u32::from_le_bytes([
 				a.to_ascii_lowercase(),
 				b.to_ascii_lowercase(),
@@ -541,6 +684,272 @@ impl Extension {
 		}
 		else { None }
 	}
+
+	/// # Slice Extensions (Plural).
+	///
+	/// This returns an iterator over _all_ of the trailing extensions in a
+	/// path (in slice form), from right to left, like `age`, `gz`, and `tar`
+	/// for `archive.tar.gz.age`.
+	///
+	/// Each segment is parsed and validated the same way as
+	/// [`Extension::slice_ext2`], [`Extension::slice_ext3`], and
+	/// [`Extension::slice_ext4`], with iteration stopping at the first one
+	/// that doesn't pass muster (too short, too long, path separator, etc.).
+	///
+	/// No allocations are made.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// let mut iter = Extension::slice_exts(b"/path/to/archive.TAR.gz.age");
+	/// assert_eq!(iter.next(), Some(Extension::new3(*b"age")));
+	/// assert_eq!(iter.next(), Some(Extension::new2(*b"gz")));
+	/// assert_eq!(iter.next(), Some(Extension::new3(*b"tar")));
+	/// assert_eq!(iter.next(), None);
+	///
+	/// // Iteration stops at the first unsupported segment.
+	/// let mut iter = Extension::slice_exts(b"/path/to/file.tar.x.gz");
+	/// assert_eq!(iter.next(), Some(Extension::new2(*b"gz")));
+	/// assert_eq!(iter.next(), None);
+	/// ```
+	pub fn slice_exts(mut src: &[u8]) -> impl Iterator<Item=Self> + '_ {
+		std::iter::from_fn(move || {
+			let dot = src.iter().rposition(|&b| matches!(b, b'.' | b'/' | b'\\'))?;
+			let ext = match src.len() - dot - 1 {
+				2 => Self::slice_ext2(src),
+				3 => Self::slice_ext3(src),
+				4 => Self::slice_ext4(src),
+				_ => None,
+			};
+
+			// Chop off the extension for next time, or if there wasn't one,
+			// everything.
+			src = if ext.is_some() { &src[..dot] } else { &[] };
+			ext
+		})
+	}
+}
+
+/// # Comparison Helpers.
+impl Extension {
+	#[must_use]
+	/// # Is One Of?
+	///
+	/// Returns `true` if `self` is equal to any of the extensions in `set`.
+	///
+	/// This is equivalent to `set.contains(&self)`, but works in `const`
+	/// contexts too, making it handy for pre-computed classification tables.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// const IMAGES: &[Extension] = &[
+	///     Extension::new3(*b"gif"),
+	///     Extension::new3(*b"jpg"),
+	///     Extension::new3(*b"png"),
+	///     Extension::new4(*b"webp"),
+	/// ];
+	///
+	/// const IS_IMAGE: bool = Extension::new3(*b"png").is_one_of(IMAGES);
+	/// assert!(IS_IMAGE);
+	///
+	/// assert!(! Extension::new4(*b"html").is_one_of(IMAGES));
+	/// assert_eq!(
+	///     Extension::try_from3("/path/to/IMAGE.JPG").is_some_and(|e| e.is_one_of(IMAGES)),
+	///     true,
+	/// );
+	/// ```
+	pub const fn is_one_of(self, set: &[Self]) -> bool {
+		let mut i = 0;
+		while i < set.len() {
+			if self.eq_const(set[i]) { return true; }
+			i += 1;
+		}
+
+		false
+	}
+
+	#[must_use]
+	/// # First Match.
+	///
+	/// Return the first extension in `candidates` matching the path, if any.
+	///
+	/// This is like testing each candidate against the path in turn — or
+	/// [`Extension::is_one_of`] in reverse — except the path is only parsed
+	/// once, and you get back _which_ extension matched, handy for choosing
+	/// a decoder or the like.
+	///
+	/// Since a path can only have one (final) extension, there will be at
+	/// most one distinct match; duplicates in `candidates` are harmless.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// const GZ: Extension = Extension::new2(*b"gz");
+	/// const TAR: Extension = Extension::new3(*b"tar");
+	/// const ZIP: Extension = Extension::new3(*b"zip");
+	///
+	/// assert_eq!(
+	///     Extension::first_match("/path/to/archive.tar.GZ", &[TAR, GZ, ZIP]),
+	///     Some(GZ),
+	/// );
+	/// assert_eq!(
+	///     Extension::first_match("/path/to/archive.tar", &[TAR, GZ, ZIP]),
+	///     Some(TAR),
+	/// );
+	/// assert_eq!(
+	///     Extension::first_match("/path/to/archive.rar", &[TAR, GZ, ZIP]),
+	///     None,
+	/// );
+	/// ```
+	pub fn first_match<P>(path: P, candidates: &[Self]) -> Option<Self>
+	where P: AsRef<Path> {
+		let ext = Self::slice_exts(path_slice!(path)).next()?;
+		candidates.iter().copied().find(|c| c.eq_const(ext))
+	}
+
+	#[must_use]
+	/// # Has Penultimate Extension?
+	///
+	/// Returns `true` if `self` matches the _second-to-last_ extension of the
+	/// path, ignoring case, as with the `pdf` in `invoice.pdf.exe`.
+	///
+	/// This is mainly useful for sniffing out files whose "real" type has
+	/// been disguised behind a second extension.
+	///
+	/// Segments are parsed the same way as [`Extension::slice_exts`], so if
+	/// the final extension isn't one `Extension` can represent, there is no
+	/// penultimate to match.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// const PDF: Extension = Extension::new3(*b"pdf");
+	///
+	/// assert!(PDF.has_penultimate("/path/to/invoice.pdf.exe"));
+	/// assert!(PDF.has_penultimate("/path/to/INVOICE.PDF.scr"));
+	///
+	/// // Nothing to see here.
+	/// assert!(! PDF.has_penultimate("/path/to/invoice.pdf"));
+	/// assert!(! PDF.has_penultimate("/path/to/invoice.exe.pdf"));
+	/// assert!(! PDF.has_penultimate("/path/to/.pdf.exe"));
+	/// ```
+	pub fn has_penultimate<P>(self, path: P) -> bool
+	where P: AsRef<Path> {
+		Self::slice_exts(path_slice!(path)).nth(1).is_some_and(|e| e.eq_const(self))
+	}
+
+	#[must_use]
+	/// # Category.
+	///
+	/// Classify the extension as an image, audio, video, archive, web asset,
+	/// document, or source code, or return `None` if it isn't one of the
+	/// well-known extensions in [`common`].
+	///
+	/// This is deliberately conservative; see [`Category`] for details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::{
+	///     Extension,
+	///     ext::common::Category,
+	/// };
+	///
+	/// assert_eq!(
+	///     Extension::try_from3("/path/to/IMAGE.JPG").and_then(Extension::category),
+	///     Some(Category::Image),
+	/// );
+	/// assert_eq!(Extension::new4(*b"flac").category(), Some(Category::Audio));
+	/// assert_eq!(Extension::new2(*b"gz").category(), Some(Category::Archive));
+	///
+	/// // Unknown.
+	/// assert_eq!(Extension::new3(*b"abc").category(), None);
+	/// ```
+	pub const fn category(self) -> Option<Category> {
+		if self.is_one_of(common::IMAGE_EXTS) { Some(Category::Image) }
+		else if self.is_one_of(common::AUDIO_EXTS) { Some(Category::Audio) }
+		else if self.is_one_of(common::VIDEO_EXTS) { Some(Category::Video) }
+		else if self.is_one_of(common::ARCHIVE_EXTS) { Some(Category::Archive) }
+		else if self.is_one_of(common::WEB_EXTS) { Some(Category::Web) }
+		else if self.is_one_of(common::DOCUMENT_EXTS) { Some(Category::Document) }
+		else if self.is_one_of(common::CODE_EXTS) { Some(Category::Code) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # Equal (Const)?
+	///
+	/// This is the same as `self == other`, but works in `const` contexts.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	///
+	/// const JPG: Extension = Extension::new3(*b"jpg");
+	/// const PNG: Extension = Extension::new3(*b"png");
+	///
+	/// const fn is_image(ext: Extension) -> bool {
+	///     ext.eq_const(JPG) || ext.eq_const(PNG)
+	/// }
+	///
+	/// const YES: bool = is_image(Extension::new3(*b"png"));
+	/// const NO: bool = is_image(Extension::new4(*b"html"));
+	/// assert!(YES);
+	/// assert!(! NO);
+	/// ```
+	pub const fn eq_const(self, other: Self) -> bool {
+		match (self, other) {
+			(Self::Ext2(e1), Self::Ext2(e2)) => e1 == e2,
+			(Self::Ext3(e1), Self::Ext3(e2)) |
+			(Self::Ext4(e1), Self::Ext4(e2)) => e1 == e2,
+			_ => false,
+		}
+	}
+
+	#[must_use]
+	/// # Matches Filename?
+	///
+	/// Returns `true` if the bare file name — as from
+	/// [`DirEntry::file_name`](std::fs::DirEntry::file_name) — ends with
+	/// this extension, ignoring case.
+	///
+	/// Unlike the path-based comparisons, this is meant for names with no
+	/// directory components whatsoever; if `name` contains a forward or
+	/// backward slash, it will never match.
+	///
+	/// As with paths, the name must have something before the dot, so
+	/// hidden files like `.txt` are not a match.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::Extension;
+	/// use std::ffi::OsStr;
+	///
+	/// const EXT: Extension = Extension::new3(*b"txt");
+	///
+	/// assert!(EXT.matches_filename(OsStr::new("a.txt")));
+	/// assert!(EXT.matches_filename(OsStr::new("README.TXT")));
+	///
+	/// assert!(! EXT.matches_filename(OsStr::new("txt")));
+	/// assert!(! EXT.matches_filename(OsStr::new(".txt")));
+	/// assert!(! EXT.matches_filename(OsStr::new("a.txt.gz")));
+	/// assert!(! EXT.matches_filename(OsStr::new("dir/a.txt")));
+	/// ```
+	pub fn matches_filename(self, name: &OsStr) -> bool {
+		! name.as_encoded_bytes().iter().any(|&b| matches!(b, b'/' | b'\\')) &&
+		self == Path::new(name)
+	}
 }
 
 /// # Codegen Helpers.
@@ -674,11 +1083,120 @@ impl Extension {
 
 
 
+#[must_use]
+/// # Path Is Windows-Safe?
+///
+/// Returns `true` if every (normal) component of the path would make a
+/// valid Windows file name, regardless of the current platform. This is
+/// handy for flagging names that won't survive a trip to a Windows
+/// filesystem.
+///
+/// Components are rejected if they:
+/// * Contain control characters or any of `<>:"|?*\`;
+/// * End with a space or period;
+/// * Have a reserved device name — `CON`, `PRN`, `AUX`, `NUL`, `COM1`-`COM9`, or `LPT1`-`LPT9` — as their stem, case-insensitively and with or without an extension;
+///
+/// Root, prefix, and `.`/`..` components are ignored.
+///
+/// ## Examples
+///
+/// ```
+/// use dowser::path_is_windows_safe;
+///
+/// assert!(path_is_windows_safe("/home/user/notes.txt"));
+/// assert!(path_is_windows_safe("/home/user/console.log"));
+///
+/// assert!(! path_is_windows_safe("/home/user/aux/notes.txt"));
+/// assert!(! path_is_windows_safe("/home/user/NUL.tar.gz"));
+/// assert!(! path_is_windows_safe("/home/user/what?.txt"));
+/// assert!(! path_is_windows_safe("/home/user/trailing."));
+/// ```
+pub fn path_is_windows_safe<P>(path: P) -> bool
+where P: AsRef<Path> {
+	path.as_ref().components().all(|c| match c {
+		Component::Normal(name) => name_is_windows_safe(name.as_encoded_bytes()),
+		_ => true,
+	})
+}
+
+/// # Name Is Windows-Safe?
+///
+/// This checks a single file name for [`path_is_windows_safe`].
+fn name_is_windows_safe(name: &[u8]) -> bool {
+	// Illegal characters.
+	if name.iter().any(|&b| b < 32 || matches!(b, b'<' | b'>' | b':' | b'"' | b'|' | b'?' | b'*' | b'\\')) {
+		return false;
+	}
+
+	// Trailing spaces or periods.
+	if matches!(name.last(), None | Some(b' ' | b'.')) { return false; }
+
+	// Reserved device names.
+	let stem = name.split(|b| b'.'.eq(b)).next().unwrap_or_default().trim_ascii_end();
+	! match stem {
+		[a, b, c] => matches!(
+			[a.to_ascii_uppercase(), b.to_ascii_uppercase(), c.to_ascii_uppercase()],
+			[b'C', b'O', b'N'] | [b'P', b'R', b'N'] | [b'A', b'U', b'X'] | [b'N', b'U', b'L']
+		),
+		[a, b, c, b'1'..=b'9'] => matches!(
+			[a.to_ascii_uppercase(), b.to_ascii_uppercase(), c.to_ascii_uppercase()],
+			[b'C', b'O', b'M'] | [b'L', b'P', b'T']
+		),
+		_ => false,
+	}
+}
+
+
+
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn t_path_is_windows_safe() {
+		for p in [
+			"/home/user/file.txt",
+			"relative/file",
+			"../up/./here",
+			"/conf/soles",
+			"CONSOLE",
+			"com10",
+			"com0",
+			"lpt",
+			"a.con",
+			".hidden",
+			"spaces are fine",
+		] {
+			assert!(path_is_windows_safe(p), "{p} should be safe.");
+		}
+
+		for p in [
+			"con",
+			"CON",
+			"Con.txt",
+			"nul.tar.gz",
+			"/home/aux/file.txt",
+			"prn ",
+			"prn .txt",
+			"COM1",
+			"lpt9.log",
+			"a<b",
+			"a>b",
+			"a:b",
+			"a\"b",
+			"a|b",
+			"a?b",
+			"a*b",
+			"a\\b",
+			"tab\tbed",
+			"trailing.",
+			"trailing ",
+		] {
+			assert!(! path_is_windows_safe(p), "{p} should not be safe.");
+		}
+	}
+
 	#[test]
 	fn t_codegen() {
 		assert_eq!(Extension::codegen(b"js"), "Extension::Ext2(29_546_u16)");
@@ -694,6 +1212,261 @@ mod tests {
 		assert_eq!(Extension::codegen(b"index.html"), "Extension::Ext4(1_819_112_552_u32)");
 	}
 
+	#[test]
+	fn t_slice_ext_consistency() {
+		// Exhaustively check all short combinations of the bytes that
+		// matter to make sure the sized and unsized parsers agree.
+		const BYTES: [u8; 6] = [b'a', b'B', b'.', b'/', b'\\', 0];
+		let mut buf = Vec::with_capacity(7);
+		for len in 0..=7_u32 {
+			for mut n in 0..BYTES.len().pow(len) {
+				buf.clear();
+				for _ in 0..len {
+					buf.push(BYTES[n % BYTES.len()]);
+					n /= BYTES.len();
+				}
+
+				let ext = Extension::slice_ext(&buf);
+				let sized = match ext.map(<[u8]>::len) {
+					Some(2) => Extension::slice_ext2(&buf),
+					Some(3) => Extension::slice_ext3(&buf),
+					Some(4) => Extension::slice_ext4(&buf),
+					_ => None,
+				};
+
+				// Only the matching size should match.
+				assert_eq!(
+					[
+						Extension::slice_ext2(&buf).is_some(),
+						Extension::slice_ext3(&buf).is_some(),
+						Extension::slice_ext4(&buf).is_some(),
+					].into_iter().filter(|b| *b).count(),
+					usize::from(sized.is_some()),
+					"Sized mismatch: {buf:?}",
+				);
+
+				// And it should be the same as the first of many.
+				assert_eq!(
+					Extension::slice_exts(&buf).next(),
+					sized,
+					"Plural mismatch: {buf:?}",
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn t_slice_exts() {
+		const AGE: Extension = Extension::new3(*b"age");
+		const GZ: Extension = Extension::new2(*b"gz");
+		const TAR: Extension = Extension::new3(*b"tar");
+
+		for (path, expected) in [
+			(&b"a.tar.gz.age"[..], &[AGE, GZ, TAR][..]),
+			(b"/path/to/a.tar.gz", &[GZ, TAR]),
+			(b"/path/to/.tar.gz", &[GZ]),
+			(b"/path/to\\.tar.gz", &[GZ]),
+			(b"/path.tar/to.gz", &[GZ]),
+			(b"/path.tar/to", &[]),
+			(b"/path/to/a.tar.", &[]),
+			(b"/path/to/a.html.tar", &[TAR, Extension::new4(*b"html")]),
+			(b"/path/to/a.xhtml.tar", &[TAR]),
+			(b"/path/to/a..gz", &[GZ]),
+			(b".gz", &[]),
+			(b"", &[]),
+		] {
+			let found: Vec<Extension> = Extension::slice_exts(path).collect();
+			assert_eq!(found, expected, "{}", String::from_utf8_lossy(path));
+		}
+	}
+
+	#[test]
+	fn t_category() {
+		const CAT: Option<Category> = common::MP3.category();
+		assert_eq!(CAT, Some(Category::Audio));
+
+		for cat in [
+			Category::Image,
+			Category::Audio,
+			Category::Video,
+			Category::Archive,
+			Category::Web,
+			Category::Document,
+			Category::Code,
+		] {
+			for e in cat.exts() {
+				// SVG is in two sets; the first wins.
+				if *e == common::SVG { assert_eq!(e.category(), Some(Category::Image)); }
+				else { assert_eq!(e.category(), Some(cat)); }
+			}
+		}
+
+		assert_eq!(Extension::new3(*b"txt").category(), Some(Category::Document));
+		assert_eq!(Extension::new2(*b"rs").category(), Some(Category::Code));
+		assert_eq!(Extension::new3(*b"dat").category(), None);
+		assert_eq!(Extension::new4(*b"rs00").category(), None);
+	}
+
+	#[test]
+	fn t_first_match() {
+		const SET: &[Extension] = &[
+			Extension::new3(*b"tar"),
+			Extension::new2(*b"gz"),
+			Extension::new4(*b"html"),
+		];
+
+		for (path, e) in [
+			("/foo/bar.tar", SET[0]),
+			("/foo/BAR.TAR", SET[0]),
+			("/foo/bar.html.tar", SET[0]),
+			("/foo/bar.tar.gz", SET[1]),
+			("/foo/bar.Gz", SET[1]),
+			("/foo/bar.html", SET[2]),
+		] {
+			assert_eq!(Extension::first_match(path, SET), Some(e), "{path}");
+		}
+
+		for path in [
+			"/foo/bar.tar.zip", "/foo/bar.gz/baz", "/foo/.gz", "/foo/bar.",
+			"/foo/bar.htm", "/foo/bar",
+		] {
+			assert_eq!(Extension::first_match(path, SET), None, "{path}");
+		}
+
+		assert_eq!(Extension::first_match("/foo/bar.gz", &[]), None);
+	}
+
+	#[test]
+	fn t_u32() {
+		for e in [
+			Extension::new2(*b"gz"),
+			Extension::new3(*b"jpg"),
+			Extension::new4(*b"html"),
+			Extension::new2(*b"ab"),
+			Extension::new3(*b"ab\xff"),
+			Extension::new4(*b"ab\xff\xff"),
+		] {
+			assert_eq!(Extension::from_u32(e.as_u32()), Some(e), "Roundtrip failed: {e:?}");
+		}
+
+		// Shorter extensions are padded with bytes that can't appear in
+		// longer ones, so should always unpack as the right kind.
+		assert!(matches!(Extension::from_u32(Extension::new3(*b"abc").as_u32()), Some(Extension::Ext3(_))));
+		assert!(matches!(Extension::from_u32(Extension::new2(*b"ab").as_u32()), Some(Extension::Ext2(_))));
+
+		// Junk.
+		for n in [
+			0,
+			u32::MAX >> 8,
+			u32::from_le_bytes(*b"a.bc"),
+			u32::from_le_bytes(*b".a/b"),
+			u32::from_le_bytes(*b"a\0bc"),
+		] {
+			assert_eq!(Extension::from_u32(n), None, "{n}");
+		}
+	}
+
+	#[test]
+	fn t_has_penultimate() {
+		const PDF: Extension = Extension::new3(*b"pdf");
+
+		for path in [
+			"invoice.pdf.exe", "/foo/Invoice.PDF.exe", "/foo/a.tar.pdf.exe",
+			"/foo/a.pdf.gz",
+		] {
+			assert!(PDF.has_penultimate(path), "Should match: {path}");
+		}
+
+		for path in [
+			"", "/foo/invoice.pdf", "/foo/invoice.exe.pdf", "/foo/.pdf.exe",
+			"/foo/a.pdf.x.exe", "/foo/a.pdf.exe1234", "/foo.pdf/a.exe",
+			"/foo/a.pdf.", "/foo/a.pdfx.exe",
+		] {
+			assert!(! PDF.has_penultimate(path), "Should not match: {path}");
+		}
+	}
+
+	#[test]
+	fn t_is_one_of() {
+		const SET: &[Extension] = &[
+			Extension::new2(*b"gz"),
+			Extension::new3(*b"jpg"),
+			Extension::new4(*b"html"),
+		];
+
+		for e in SET { assert!(e.is_one_of(SET)); }
+		assert!(! Extension::new2(*b"br").is_one_of(SET));
+		assert!(! Extension::new3(*b"htm").is_one_of(SET));
+		assert!(! Extension::new4(*b"jpeg").is_one_of(SET));
+		assert!(! Extension::new2(*b"gz").is_one_of(&[]));
+
+		// Make sure variants don't cross-match on shared numbers.
+		let ext3 = Extension::Ext3(u32::from_le_bytes(*b"html"));
+		assert!(! ext3.is_one_of(SET));
+	}
+
+	#[test]
+	fn t_eq_const() {
+		const GZ: Extension = Extension::new2(*b"gz");
+		const JPG: Extension = Extension::new3(*b"jpg");
+		const HTML: Extension = Extension::new4(*b"html");
+
+		let all = [GZ, JPG, HTML, Extension::Ext3(u32::from_le_bytes(*b"html"))];
+		for a in all {
+			for b in all {
+				assert_eq!(a.eq_const(b), a == b, "{a:?} vs {b:?}");
+			}
+		}
+
+		// And patterns.
+		for (path, expected) in [
+			("/a/b.JPG", true),
+			("/a/b.gz", true),
+			("/a/b.html", false),
+			("/a/b", false),
+		] {
+			let ext = Extension::try_from2(path).or_else(|| Extension::try_from3(path));
+			assert_eq!(matches!(ext, Some(GZ | JPG)), expected, "{path}");
+		}
+	}
+
+	#[test]
+	fn t_parse_lenient() {
+		for (raw, expected) in [
+			("gz", Extension::new2(*b"gz")),
+			(".GZ", Extension::new2(*b"gz")),
+			("Png", Extension::new3(*b"png")),
+			(".png", Extension::new3(*b"png")),
+			("html", Extension::new4(*b"html")),
+			(".hTmL", Extension::new4(*b"html")),
+		] {
+			assert_eq!(Extension::parse_lenient(raw), Some(expected), "{raw}");
+		}
+
+		for raw in ["", ".", "..", "g", ".g", "..gz", "g.z", "gz.", "html5", "/gz", "g\\z", " gz", "g\tz"] {
+			assert_eq!(Extension::parse_lenient(raw), None, "{raw:?}");
+		}
+
+		// Parsing should agree with path-based extraction.
+		assert_eq!(Extension::parse_lenient(".jpeg"), Extension::try_from4("a.JPEG"));
+	}
+
+	#[test]
+	fn t_matches_filename() {
+		const EXT: Extension = Extension::new3(*b"txt");
+
+		for name in ["a.txt", "A.TXT", "a.b.txt", "..txt", "a .Txt"] {
+			assert!(EXT.matches_filename(OsStr::new(name)), "Should match: {name}");
+		}
+
+		for name in [
+			"", "txt", ".txt", "a.txt.", "a.txtx", "a.tx", "a/b.txt", "a\\b.txt",
+			"a.txt/",
+		] {
+			assert!(! EXT.matches_filename(OsStr::new(name)), "Should not match: {name}");
+		}
+	}
+
 	#[test]
 	#[should_panic]
 	fn t_codegen_bad1() { let _res = Extension::codegen(b""); }
@@ -0,0 +1,476 @@
+/*!
+# Dowser: Common Extensions
+
+This module contains pre-computed [`Extension`] constants for some of the
+most frequently-encountered file types, along with per-category slices
+suitable for use with [`Extension::is_one_of`], and the [`Category`] enum
+returned by [`Extension::category`].
+
+## Examples
+
+```
+use dowser::{
+    Extension,
+    ext::common::{
+        Category,
+        IMAGE_EXTS,
+        JPG,
+    },
+};
+
+assert_eq!(Extension::try_from3("/path/to/IMAGE.JPG"), Some(JPG));
+assert!(JPG.is_one_of(IMAGE_EXTS));
+assert_eq!(JPG.category(), Some(Category::Image));
+```
+*/
+
+use crate::Extension;
+
+
+
+// Images.
+
+/// # `.avif`
+pub const AVIF: Extension = Extension::new4(*b"avif");
+
+/// # `.bmp`
+pub const BMP: Extension = Extension::new3(*b"bmp");
+
+/// # `.gif`
+pub const GIF: Extension = Extension::new3(*b"gif");
+
+/// # `.heic`
+pub const HEIC: Extension = Extension::new4(*b"heic");
+
+/// # `.ico`
+pub const ICO: Extension = Extension::new3(*b"ico");
+
+/// # `.jpeg`
+pub const JPEG: Extension = Extension::new4(*b"jpeg");
+
+/// # `.jpg`
+pub const JPG: Extension = Extension::new3(*b"jpg");
+
+/// # `.jxl`
+pub const JXL: Extension = Extension::new3(*b"jxl");
+
+/// # `.png`
+pub const PNG: Extension = Extension::new3(*b"png");
+
+/// # `.svg`
+pub const SVG: Extension = Extension::new3(*b"svg");
+
+/// # `.tif`
+pub const TIF: Extension = Extension::new3(*b"tif");
+
+/// # `.tiff`
+pub const TIFF: Extension = Extension::new4(*b"tiff");
+
+/// # `.webp`
+pub const WEBP: Extension = Extension::new4(*b"webp");
+
+
+// Audio.
+
+/// # `.aac`
+pub const AAC: Extension = Extension::new3(*b"aac");
+
+/// # `.aiff`
+pub const AIFF: Extension = Extension::new4(*b"aiff");
+
+/// # `.flac`
+pub const FLAC: Extension = Extension::new4(*b"flac");
+
+/// # `.m4a`
+pub const M4A: Extension = Extension::new3(*b"m4a");
+
+/// # `.mp3`
+pub const MP3: Extension = Extension::new3(*b"mp3");
+
+/// # `.oga`
+pub const OGA: Extension = Extension::new3(*b"oga");
+
+/// # `.ogg`
+pub const OGG: Extension = Extension::new3(*b"ogg");
+
+/// # `.opus`
+pub const OPUS: Extension = Extension::new4(*b"opus");
+
+/// # `.wav`
+pub const WAV: Extension = Extension::new3(*b"wav");
+
+
+// Video.
+
+/// # `.avi`
+pub const AVI: Extension = Extension::new3(*b"avi");
+
+/// # `.m4v`
+pub const M4V: Extension = Extension::new3(*b"m4v");
+
+/// # `.mkv`
+pub const MKV: Extension = Extension::new3(*b"mkv");
+
+/// # `.mov`
+pub const MOV: Extension = Extension::new3(*b"mov");
+
+/// # `.mp4`
+pub const MP4: Extension = Extension::new3(*b"mp4");
+
+/// # `.mpeg`
+pub const MPEG: Extension = Extension::new4(*b"mpeg");
+
+/// # `.mpg`
+pub const MPG: Extension = Extension::new3(*b"mpg");
+
+/// # `.ogv`
+pub const OGV: Extension = Extension::new3(*b"ogv");
+
+/// # `.webm`
+pub const WEBM: Extension = Extension::new4(*b"webm");
+
+/// # `.wmv`
+pub const WMV: Extension = Extension::new3(*b"wmv");
+
+
+// Archives.
+
+/// # `.br`
+pub const BR: Extension = Extension::new2(*b"br");
+
+/// # `.bz2`
+pub const BZ2: Extension = Extension::new3(*b"bz2");
+
+/// # `.gz`
+pub const GZ: Extension = Extension::new2(*b"gz");
+
+/// # `.lz`
+pub const LZ: Extension = Extension::new2(*b"lz");
+
+/// # `.lzma`
+pub const LZMA: Extension = Extension::new4(*b"lzma");
+
+/// # `.rar`
+pub const RAR: Extension = Extension::new3(*b"rar");
+
+/// # `.tar`
+pub const TAR: Extension = Extension::new3(*b"tar");
+
+/// # `.tbz`
+pub const TBZ: Extension = Extension::new3(*b"tbz");
+
+/// # `.tgz`
+pub const TGZ: Extension = Extension::new3(*b"tgz");
+
+/// # `.txz`
+pub const TXZ: Extension = Extension::new3(*b"txz");
+
+/// # `.xz`
+pub const XZ: Extension = Extension::new2(*b"xz");
+
+/// # `.zip`
+pub const ZIP: Extension = Extension::new3(*b"zip");
+
+/// # `.zst`
+pub const ZST: Extension = Extension::new3(*b"zst");
+
+
+// Web.
+
+/// # `.css`
+pub const CSS: Extension = Extension::new3(*b"css");
+
+/// # `.htm`
+pub const HTM: Extension = Extension::new3(*b"htm");
+
+/// # `.html`
+pub const HTML: Extension = Extension::new4(*b"html");
+
+/// # `.js`
+pub const JS: Extension = Extension::new2(*b"js");
+
+/// # `.json`
+pub const JSON: Extension = Extension::new4(*b"json");
+
+/// # `.map`
+pub const MAP: Extension = Extension::new3(*b"map");
+
+/// # `.mjs`
+pub const MJS: Extension = Extension::new3(*b"mjs");
+
+/// # `.wasm`
+pub const WASM: Extension = Extension::new4(*b"wasm");
+
+/// # `.xml`
+pub const XML: Extension = Extension::new3(*b"xml");
+
+
+
+// Documents.
+
+/// # `.csv`
+pub const CSV: Extension = Extension::new3(*b"csv");
+
+/// # `.doc`
+pub const DOC: Extension = Extension::new3(*b"doc");
+
+/// # `.docx`
+pub const DOCX: Extension = Extension::new4(*b"docx");
+
+/// # `.epub`
+pub const EPUB: Extension = Extension::new4(*b"epub");
+
+/// # `.md`
+pub const MD: Extension = Extension::new2(*b"md");
+
+/// # `.odp`
+pub const ODP: Extension = Extension::new3(*b"odp");
+
+/// # `.ods`
+pub const ODS: Extension = Extension::new3(*b"ods");
+
+/// # `.odt`
+pub const ODT: Extension = Extension::new3(*b"odt");
+
+/// # `.pdf`
+pub const PDF: Extension = Extension::new3(*b"pdf");
+
+/// # `.ppt`
+pub const PPT: Extension = Extension::new3(*b"ppt");
+
+/// # `.pptx`
+pub const PPTX: Extension = Extension::new4(*b"pptx");
+
+/// # `.rtf`
+pub const RTF: Extension = Extension::new3(*b"rtf");
+
+/// # `.txt`
+pub const TXT: Extension = Extension::new3(*b"txt");
+
+/// # `.xls`
+pub const XLS: Extension = Extension::new3(*b"xls");
+
+/// # `.xlsx`
+pub const XLSX: Extension = Extension::new4(*b"xlsx");
+
+
+
+// Source Code.
+
+/// # `.cpp`
+pub const CPP: Extension = Extension::new3(*b"cpp");
+
+/// # `.cs`
+pub const CS: Extension = Extension::new2(*b"cs");
+
+/// # `.go`
+pub const GO: Extension = Extension::new2(*b"go");
+
+/// # `.hpp`
+pub const HPP: Extension = Extension::new3(*b"hpp");
+
+/// # `.java`
+pub const JAVA: Extension = Extension::new4(*b"java");
+
+/// # `.kt`
+pub const KT: Extension = Extension::new2(*b"kt");
+
+/// # `.lua`
+pub const LUA: Extension = Extension::new3(*b"lua");
+
+/// # `.php`
+pub const PHP: Extension = Extension::new3(*b"php");
+
+/// # `.py`
+pub const PY: Extension = Extension::new2(*b"py");
+
+/// # `.rb`
+pub const RB: Extension = Extension::new2(*b"rb");
+
+/// # `.rs`
+pub const RS: Extension = Extension::new2(*b"rs");
+
+/// # `.sh`
+pub const SH: Extension = Extension::new2(*b"sh");
+
+/// # `.ts`
+pub const TS: Extension = Extension::new2(*b"ts");
+
+/// # `.tsx`
+pub const TSX: Extension = Extension::new3(*b"tsx");
+
+
+
+/// # Images.
+pub const IMAGE_EXTS: &[Extension] = &[
+	AVIF,
+	BMP,
+	GIF,
+	HEIC,
+	ICO,
+	JPEG,
+	JPG,
+	JXL,
+	PNG,
+	SVG,
+	TIF,
+	TIFF,
+	WEBP,
+];
+
+/// # Audio.
+pub const AUDIO_EXTS: &[Extension] = &[
+	AAC,
+	AIFF,
+	FLAC,
+	M4A,
+	MP3,
+	OGA,
+	OGG,
+	OPUS,
+	WAV,
+];
+
+/// # Video.
+pub const VIDEO_EXTS: &[Extension] = &[
+	AVI,
+	M4V,
+	MKV,
+	MOV,
+	MP4,
+	MPEG,
+	MPG,
+	OGV,
+	WEBM,
+	WMV,
+];
+
+/// # Archives.
+pub const ARCHIVE_EXTS: &[Extension] = &[
+	BR,
+	BZ2,
+	GZ,
+	LZ,
+	LZMA,
+	RAR,
+	TAR,
+	TBZ,
+	TGZ,
+	TXZ,
+	XZ,
+	ZIP,
+	ZST,
+];
+
+/// # Web.
+pub const WEB_EXTS: &[Extension] = &[
+	CSS,
+	HTM,
+	HTML,
+	JS,
+	JSON,
+	MAP,
+	MJS,
+	SVG,
+	WASM,
+	XML,
+];
+
+/// # Documents.
+pub const DOCUMENT_EXTS: &[Extension] = &[
+	CSV,
+	DOC,
+	DOCX,
+	EPUB,
+	MD,
+	ODP,
+	ODS,
+	ODT,
+	PDF,
+	PPT,
+	PPTX,
+	RTF,
+	TXT,
+	XLS,
+	XLSX,
+];
+
+/// # Source Code.
+pub const CODE_EXTS: &[Extension] = &[
+	CPP,
+	CS,
+	GO,
+	HPP,
+	JAVA,
+	KT,
+	LUA,
+	PHP,
+	PY,
+	RB,
+	RS,
+	SH,
+	TS,
+	TSX,
+];
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Extension Category.
+///
+/// This is a broad classification of the [`Extension`]s in this module,
+/// as returned by [`Extension::category`].
+///
+/// Each variant corresponds to one of the per-category slices, e.g.
+/// [`Category::Image`] and [`IMAGE_EXTS`]. Extensions appearing in more than
+/// one slice — `.svg` is both an image and a web asset — belong to the
+/// first matching variant, in declaration order.
+pub enum Category {
+	/// # Images.
+	Image,
+
+	/// # Audio.
+	Audio,
+
+	/// # Video.
+	Video,
+
+	/// # Archives and Compression.
+	Archive,
+
+	/// # Web Assets.
+	Web,
+
+	/// # Documents.
+	Document,
+
+	/// # Source Code.
+	Code,
+}
+
+impl Category {
+	#[must_use]
+	/// # Extensions.
+	///
+	/// Return the full set of extensions belonging to the category (or
+	/// more precisely, the corresponding slice).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dowser::ext::common::{Category, IMAGE_EXTS};
+	///
+	/// assert_eq!(Category::Image.exts(), IMAGE_EXTS);
+	/// ```
+	pub const fn exts(self) -> &'static [Extension] {
+		match self {
+			Self::Image => IMAGE_EXTS,
+			Self::Audio => AUDIO_EXTS,
+			Self::Video => VIDEO_EXTS,
+			Self::Archive => ARCHIVE_EXTS,
+			Self::Web => WEB_EXTS,
+			Self::Document => DOCUMENT_EXTS,
+			Self::Code => CODE_EXTS,
+		}
+	}
+}
@@ -0,0 +1,275 @@
+/*!
+# Dowser: Extension Map
+*/
+
+use crate::Extension;
+use std::path::Path;
+
+
+
+#[macro_export]
+/// # Extension Map.
+///
+/// Build an [`ExtensionMap`](crate::ExtensionMap) from `"ext" => value`
+/// pairs, at compile time if desired.
+///
+/// Extensions must be two, three, or four bytes, without the leading
+/// period. Casing is fixed automatically. Invalid or duplicate extensions
+/// will cause a panic — or in `const`/`static` contexts, a compile error.
+///
+/// ## Examples
+///
+/// ```
+/// use dowser::{Extension, ExtensionMap, extension_map};
+///
+/// /// # Image Kinds.
+/// static IMAGES: ExtensionMap<&str, 4> = extension_map! {
+///     "jpg" => "JPEG",
+///     "jpeg" => "JPEG",
+///     "png" => "PNG",
+///     "gz" => "Gzip",
+/// };
+///
+/// assert_eq!(IMAGES.get(Extension::new3(*b"png")), Some(&"PNG"));
+/// assert_eq!(IMAGES.get_path("/path/to/IMAGE.JPEG"), Some(&"JPEG"));
+/// assert_eq!(IMAGES.get_path("/path/to/image.gif"), None);
+/// ```
+macro_rules! extension_map {
+	($($ext:literal => $val:expr),* $(,)?) => (
+		$crate::ExtensionMap::new([$($ext),*], [$($val),*])
+	);
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Extension Map.
+///
+/// This is a compact, allocation-free, read-only map keyed by [`Extension`],
+/// suitable for `const` and `static` use. It is generally easiest to build
+/// with the [`extension_map!`](crate::extension_map) macro.
+///
+/// Keys are stored [packed](Extension::as_u32) and sorted, so lookups are a
+/// simple binary search over a flat array of integers, making dispatch on
+/// extension faster than a runtime `HashMap`.
+///
+/// ## Examples
+///
+/// ```
+/// use dowser::{ExtensionMap, extension_map};
+/// use std::path::Path;
+///
+/// /// # Decode JPEG.
+/// fn decode_jpg(_: &Path) -> usize { 1 }
+///
+/// /// # Decode PNG.
+/// fn decode_png(_: &Path) -> usize { 2 }
+///
+/// static HANDLERS: ExtensionMap<fn(&Path) -> usize, 3> = extension_map! {
+///     "jpg" => decode_jpg,
+///     "jpeg" => decode_jpg,
+///     "png" => decode_png,
+/// };
+///
+/// let path = Path::new("/path/to/image.png");
+/// if let Some(cb) = HANDLERS.get_path(path) {
+///     assert_eq!(cb(path), 2);
+/// }
+/// ```
+pub struct ExtensionMap<V, const N: usize> {
+	/// # Packed Extensions (Sorted).
+	keys: [u32; N],
+
+	/// # Value Indexes.
+	///
+	/// The position in `values` for each key.
+	idx: [usize; N],
+
+	/// # Values (Unsorted).
+	values: [V; N],
+}
+
+impl<V, const N: usize> ExtensionMap<V, N> {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new map from parallel arrays of extensions and values. See
+	/// [`extension_map!`](crate::extension_map) for a friendlier syntax.
+	///
+	/// ## Panics
+	///
+	/// This will panic if any of the extensions are invalid or duplicated.
+	/// (In `const` contexts, this is caught at compile time instead.)
+	pub const fn new(exts: [&str; N], values: [V; N]) -> Self {
+		let mut keys = [0_u32; N];
+		let mut idx = [0_usize; N];
+
+		// Parse and insertion-sort the keys.
+		let mut i = 0;
+		while i < N {
+			let key = parse(exts[i]).as_u32();
+			let mut j = i;
+			while 0 < j && key < keys[j - 1] {
+				keys[j] = keys[j - 1];
+				idx[j] = idx[j - 1];
+				j -= 1;
+			}
+			keys[j] = key;
+			idx[j] = i;
+			i += 1;
+		}
+
+		// Make sure there are no duplicates.
+		let mut i = 1;
+		while i < N {
+			assert!(keys[i - 1] != keys[i], "Duplicate extension.");
+			i += 1;
+		}
+
+		Self { keys, idx, values }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Length.
+	pub const fn len(&self) -> usize { N }
+
+	#[must_use]
+	#[inline]
+	/// # Is Empty?
+	pub const fn is_empty(&self) -> bool { N == 0 }
+
+	#[must_use]
+	/// # Get.
+	///
+	/// Return the value associated with the extension, if any.
+	pub fn get(&self, ext: Extension) -> Option<&V> {
+		let pos = self.keys.binary_search(&ext.as_u32()).ok()?;
+		self.values.get(*self.idx.get(pos)?)
+	}
+
+	#[must_use]
+	/// # Get By Path.
+	///
+	/// Return the value associated with the path's extension, if any. As
+	/// with [`Extension::try_from3`] and kin, casing is ignored.
+	pub fn get_path<P>(&self, path: P) -> Option<&V>
+	where P: AsRef<Path> {
+		let path = path.as_ref();
+		let ext = Extension::try_from3(path)
+			.or_else(|| Extension::try_from4(path))
+			.or_else(|| Extension::try_from2(path))?;
+		self.get(ext)
+	}
+
+	/// # Entries.
+	///
+	/// Return an iterator over the extensions and values, in packed key
+	/// order.
+	pub fn entries(&self) -> impl Iterator<Item=(Extension, &V)> {
+		self.keys.iter().zip(self.idx.iter()).filter_map(|(&k, &i)|
+			Some((Extension::from_u32(k)?, self.values.get(i)?))
+		)
+	}
+}
+
+
+
+/// # Parse Extension.
+///
+/// Build an [`Extension`] from a string at compile time, fixing the case.
+///
+/// ## Panics
+///
+/// This will panic if the extension is the wrong length, or contains
+/// periods, slashes, or `NUL` bytes.
+const fn parse(src: &str) -> Extension {
+	let src = src.as_bytes();
+	let ext = match *src {
+		[a, b] => Extension::new2([
+			a.to_ascii_lowercase(),
+			b.to_ascii_lowercase(),
+		]),
+		[a, b, c] => Extension::new3([
+			a.to_ascii_lowercase(),
+			b.to_ascii_lowercase(),
+			c.to_ascii_lowercase(),
+		]),
+		[a, b, c, d] => Extension::new4([
+			a.to_ascii_lowercase(),
+			b.to_ascii_lowercase(),
+			c.to_ascii_lowercase(),
+			d.to_ascii_lowercase(),
+		]),
+		_ => panic!("Extensions must be 2-4 bytes."),
+	};
+
+	// Run it through the unpacker to validate the bytes.
+	match Extension::from_u32(ext.as_u32()) {
+		Some(ext) => ext,
+		None => panic!("Invalid extension."),
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_map() {
+		/// # Test Map.
+		const MAP: ExtensionMap<u8, 5> = extension_map! {
+			"png" => 3,
+			"GZ" => 2,
+			"html" => 4,
+			"jpg" => 1,
+			"br" => 5,
+		};
+		assert_eq!(MAP.len(), 5);
+		assert!(! MAP.is_empty());
+
+		assert_eq!(MAP.get(Extension::new3(*b"jpg")), Some(&1));
+		assert_eq!(MAP.get(Extension::new2(*b"gz")), Some(&2));
+		assert_eq!(MAP.get(Extension::new3(*b"png")), Some(&3));
+		assert_eq!(MAP.get(Extension::new4(*b"html")), Some(&4));
+		assert_eq!(MAP.get(Extension::new2(*b"br")), Some(&5));
+		assert_eq!(MAP.get(Extension::new3(*b"gif")), None);
+
+		assert_eq!(MAP.get_path("/foo/bar.PNG"), Some(&3));
+		assert_eq!(MAP.get_path("/foo/bar.tar.gz"), Some(&2));
+		assert_eq!(MAP.get_path("/foo/index.html"), Some(&4));
+		assert_eq!(MAP.get_path("/foo/bar.jpeg"), None);
+		assert_eq!(MAP.get_path("/foo/png"), None);
+
+		// Iteration should cover everything, in key order.
+		let all: Vec<(Extension, u8)> = MAP.entries().map(|(e, v)| (e, *v)).collect();
+		assert_eq!(all.len(), 5);
+		assert!(all.windows(2).all(|w| w[0].0.as_u32() < w[1].0.as_u32()));
+		for (e, v) in all { assert_eq!(MAP.get(e), Some(&v)); }
+
+		// Empty is fine too.
+		let empty: ExtensionMap<u8, 0> = extension_map! {};
+		assert!(empty.is_empty());
+		assert_eq!(empty.get(Extension::new3(*b"jpg")), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "Duplicate extension.")]
+	fn t_map_dupe() {
+		let _map: ExtensionMap<u8, 2> = extension_map! { "jpg" => 1, "JPG" => 2 };
+	}
+
+	#[test]
+	#[should_panic(expected = "Extensions must be 2-4 bytes.")]
+	fn t_map_len() {
+		let _map: ExtensionMap<u8, 1> = extension_map! { "jpeg2" => 1 };
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid extension.")]
+	fn t_map_invalid() {
+		let _map: ExtensionMap<u8, 1> = extension_map! { "t.z" => 1 };
+	}
+}
@@ -0,0 +1,239 @@
+/*!
+# Dowser: Ignore Files
+*/
+
+use std::{
+	collections::HashMap,
+	ffi::OsString,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+
+
+#[derive(Debug, Clone, Default)]
+/// # Ignore Files.
+///
+/// This holds the name of the per-directory ignore file to look for, if
+/// any, along with the patterns collected from each one found so far.
+pub(super) struct Ignores {
+	/// # File Name.
+	name: Option<OsString>,
+
+	/// # Rule Sets.
+	///
+	/// The patterns from each ignore file, keyed by the directory it was in.
+	sets: HashMap<PathBuf, Vec<Pattern>>,
+}
+
+impl Ignores {
+	/// # Set File Name.
+	pub(super) fn set_name(&mut self, name: &str) {
+		self.name = if name.is_empty() { None } else { Some(OsString::from(name)) };
+	}
+
+	/// # Load Directory.
+	///
+	/// Check `dir` for an ignore file, and if there is one, save its
+	/// patterns for later.
+	pub(super) fn load(&mut self, dir: &Path) {
+		let Some(name) = self.name.as_deref() else { return; };
+		let Ok(raw) = std::fs::read_to_string(dir.join(name)) else { return; };
+		let patterns: Vec<Pattern> = raw.lines().filter_map(Pattern::new).collect();
+		if ! patterns.is_empty() { self.sets.insert(dir.to_path_buf(), patterns); }
+	}
+
+	/// # Is Ignored?
+	///
+	/// Returns `true` if the path — or the symlink that led to it — matches
+	/// any of the patterns from an ignore file in one of its ancestor
+	/// directories.
+	pub(super) fn is_ignored(&self, path: &Path, link: Option<&Path>) -> bool {
+		! self.sets.is_empty() &&
+		[Some(path), link].into_iter().flatten().any(|p| self.is_ignored_path(p))
+	}
+
+	/// # Is Ignored (Single Path)?
+	///
+	/// Walk up the path's ancestors, checking the patterns for any that had
+	/// ignore files of their own.
+	fn is_ignored_path(&self, path: &Path) -> bool {
+		path.ancestors().skip(1).any(|dir|
+			self.sets.get(dir).is_some_and(|patterns|
+				path.strip_prefix(dir).is_ok_and(|rel|
+					patterns.iter().any(|pat| pat.matches(rel))
+				)
+			)
+		)
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Ignore Pattern.
+struct Pattern {
+	/// # Pattern.
+	src: Box<[u8]>,
+
+	/// # Match Full (Relative) Path?
+	///
+	/// If `false`, only the file name is matched.
+	anchored: bool,
+}
+
+impl Pattern {
+	/// # New.
+	///
+	/// Parse a single line from an ignore file, returning `None` if it is
+	/// empty or a comment.
+	fn new(line: &str) -> Option<Self> {
+		let line = line.trim();
+		if line.starts_with('#') { return None; }
+
+		let line = line.trim_end_matches('/');
+		let anchored = line.contains('/');
+		let line = line.trim_start_matches('/');
+		if line.is_empty() { None }
+		else {
+			Some(Self { src: Box::from(line.as_bytes()), anchored })
+		}
+	}
+
+	/// # Matches?
+	///
+	/// Test the pattern against a path relative to the ignore file's
+	/// directory.
+	fn matches(&self, rel: &Path) -> bool {
+		if self.anchored { glob(&self.src, &path_bytes(rel)) }
+		else {
+			rel.file_name().is_some_and(|n| glob(&self.src, &path_bytes(Path::new(n))))
+		}
+	}
+}
+
+
+
+#[cfg(unix)]
+/// # Path to Bytes.
+fn path_bytes(path: &Path) -> std::borrow::Cow<'_, [u8]> {
+	use std::os::unix::ffi::OsStrExt;
+	std::borrow::Cow::Borrowed(path.as_os_str().as_bytes())
+}
+
+#[cfg(not(unix))]
+/// # Path to Bytes.
+///
+/// Separators are normalized to forward slashes to match the patterns.
+fn path_bytes(path: &Path) -> std::borrow::Cow<'_, [u8]> {
+	std::borrow::Cow::Owned(
+		path.to_string_lossy().bytes().map(|b| if b == b'\\' { b'/' } else { b }).collect()
+	)
+}
+
+/// # Glob Match.
+///
+/// Returns `true` if `pat` matches the entirety of `txt`, where `*` matches
+/// any run of bytes and `?` any single byte, other than a forward slash.
+/// Everything else is literal.
+fn glob(pat: &[u8], txt: &[u8]) -> bool {
+	let mut p = 0;
+	let mut t = 0;
+	let mut star: Option<(usize, usize)> = None;
+
+	while t < txt.len() {
+		match pat.get(p) {
+			Some(b'*') => {
+				star = Some((p, t));
+				p += 1;
+				continue;
+			},
+			Some(&c) if c == txt[t] || (c == b'?' && txt[t] != b'/') => {
+				p += 1;
+				t += 1;
+				continue;
+			},
+			_ => {},
+		}
+
+		// Backtrack, letting the last star eat one more byte.
+		match star {
+			Some((sp, st)) if txt[st] != b'/' => {
+				star = Some((sp, st + 1));
+				p = sp + 1;
+				t = st + 1;
+			},
+			_ => return false,
+		}
+	}
+
+	pat[p..].iter().all(|&b| b == b'*')
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_glob() {
+		for (pat, txt) in [
+			("foo", "foo"),
+			("*", "foo"),
+			("*", ""),
+			("f*", "foo"),
+			("*o", "foo"),
+			("f?o", "foo"),
+			("*.log", "error.log"),
+			("*.log", "a.b.log"),
+			("a/*/c", "a/b/c"),
+			("a/*", "a/b"),
+			("**", "foo"),
+			("a*b*c", "aXbYc"),
+		] {
+			assert!(glob(pat.as_bytes(), txt.as_bytes()), "{pat} should match {txt}");
+		}
+
+		for (pat, txt) in [
+			("foo", "fo"),
+			("foo", "foobar"),
+			("foo", "xfoo"),
+			("f?o", "fo"),
+			("*.log", "error.txt"),
+			("a/*", "a/b/c"),
+			("a*", "a/b"),
+			("a?b", "a/b"),
+			("", "foo"),
+		] {
+			assert!(! glob(pat.as_bytes(), txt.as_bytes()), "{pat} should not match {txt}");
+		}
+	}
+
+	#[test]
+	fn t_pattern() {
+		for line in ["", "  ", "# Comment", "/", "//"] {
+			assert!(Pattern::new(line).is_none(), "{line:?} should not be a pattern.");
+		}
+
+		// Name patterns match at any depth.
+		let pat = Pattern::new(" build/ ").expect("Pattern failed.");
+		assert!(! pat.anchored);
+		assert!(pat.matches(Path::new("build")));
+		assert!(pat.matches(Path::new("a/b/build")));
+		assert!(! pat.matches(Path::new("build/a")));
+
+		// Path patterns only match from the top.
+		let pat = Pattern::new("/build").expect("Pattern failed.");
+		assert!(pat.anchored);
+		assert!(pat.matches(Path::new("build")));
+		assert!(! pat.matches(Path::new("a/build")));
+
+		let pat = Pattern::new("a/*.log").expect("Pattern failed.");
+		assert!(pat.matches(Path::new("a/error.log")));
+		assert!(! pat.matches(Path::new("error.log")));
+		assert!(! pat.matches(Path::new("b/a/error.log")));
+	}
+}
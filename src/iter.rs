@@ -2,20 +2,164 @@
 # Dowser: Dowser
 */
 
-use crate::Entry;
+use crate::{
+	DirSource,
+	Entry,
+	entry::{
+		is_transient,
+		Retry,
+	},
+	Extension,
+	ignore::Ignores,
+	Order,
+	PermissionPolicy,
+	SharedSeen,
+	Visitor,
+};
 use dactyl::NoHash;
 use std::{
-	collections::HashSet,
-	ffi::OsStr,
+	borrow::Cow,
+	collections::{
+		hash_map::Entry as MapEntry,
+		HashMap,
+		HashSet,
+		VecDeque,
+	},
+	ffi::{
+		OsStr,
+		OsString,
+	},
+	fmt,
+	fs::File,
+	io::{
+		ErrorKind,
+		Read,
+		Write,
+	},
 	path::{
 		Path,
 		PathBuf,
 	},
+	sync::{
+		Arc,
+		Mutex,
+	},
+	time::{
+		Duration,
+		Instant,
+	},
 };
 
 
 
+#[cfg(feature = "tracing")]
+/// # Helper: Trace Event.
+///
+/// Emit a `tracing` event at the given level under the `dowser` target.
+macro_rules! trace_event {
+	($lvl:ident, $($arg:tt)+) => (tracing::$lvl!(target: "dowser", $($arg)+));
+}
+
+#[cfg(not(feature = "tracing"))]
+/// # Helper: Trace Event (Noop).
+///
+/// Without the `tracing` feature, events compile down to nothing.
+macro_rules! trace_event {
+	($($arg:tt)+) => (());
+}
+
+
+
+/// # Callback.
+///
+/// This wraps a shareable `Fn(&T) -> bool` so that [`Dowser`] can keep on
+/// deriving `Clone` and `Debug`.
+struct Callback<T: ?Sized>(Arc<dyn Fn(&T) -> bool + Send + Sync>);
+
+impl<T: ?Sized> Clone for Callback<T> {
+	#[inline]
+	fn clone(&self) -> Self { Self(Arc::clone(&self.0)) }
+}
+
+impl<T: ?Sized> fmt::Debug for Callback<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("Callback")
+	}
+}
+
+impl<T: ?Sized> Callback<T> {
+	#[inline]
+	/// # New.
+	fn new<F>(cb: F) -> Self
+	where F: Fn(&T) -> bool + Send + Sync + 'static { Self(Arc::new(cb)) }
+
+	#[inline]
+	/// # Call.
+	fn call(&self, v: &T) -> bool { (self.0)(v) }
+}
+
+impl<T: ?Sized + 'static> Callback<T> {
+	/// # And.
+	///
+	/// Wrap `self` and `cb` into a new callback that passes only when both
+	/// do, `self` first.
+	fn and<F>(self, cb: F) -> Self
+	where F: Fn(&T) -> bool + Send + Sync + 'static {
+		Self::new(move |v| self.call(v) && cb(v))
+	}
+}
+
+
+
+/// # Source.
+///
+/// This wraps a shareable [`DirSource`] so that [`Dowser`] can keep on
+/// deriving `Clone` and `Debug`.
+struct Source(Arc<dyn DirSource>);
+
+impl Clone for Source {
+	#[inline]
+	fn clone(&self) -> Self { Self(Arc::clone(&self.0)) }
+}
+
+impl fmt::Debug for Source {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("Source")
+	}
+}
+
+
+
+/// # Lazy Paths.
+///
+/// This wraps an unresolved root iterator so that [`Dowser`] can keep on
+/// deriving `Clone` and `Debug`.
+///
+/// Note: clones share the same iterator, so whichever pulls a given root
+/// first gets it. Internal methods that fan a crawl out across clones must
+/// call [`Dowser::resolve_lazy`] first.
+struct LazyPaths(Arc<Mutex<dyn Iterator<Item=PathBuf> + Send>>);
+
+impl Clone for LazyPaths {
+	#[inline]
+	fn clone(&self) -> Self { Self(Arc::clone(&self.0)) }
+}
+
+impl fmt::Debug for LazyPaths {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("LazyPaths")
+	}
+}
+
+impl LazyPaths {
+	/// # Next.
+	fn next(&self) -> Option<PathBuf> { self.0.lock().ok()?.next() }
+}
+
+
+
 #[derive(Debug, Clone)]
+#[expect(clippy::struct_excessive_bools, reason = "These are independent settings.")]
 /// # Dowser.
 ///
 /// `Dowser` is a very simple recursive file iterator. Symlinks and hidden
@@ -56,22 +200,336 @@ pub struct Dowser {
 	/// # Found Files.
 	files: Vec<PathBuf>,
 
-	/// # Found Directories.
-	dirs: Vec<PathBuf>,
+	/// # Found File Depths.
+	///
+	/// When tracking — only [`Dowser::deepest`] needs to — this holds the
+	/// depth of each file in `files`, in the same order.
+	file_depths: Option<Vec<usize>>,
+
+	/// # Found Directories (and Their Depths).
+	///
+	/// Directories are always popped from the back; where they're pushed
+	/// depends on the traversal order.
+	dirs: VecDeque<(PathBuf, usize)>,
+
+	/// # Traversal Order.
+	order: Order,
+
+	/// # Lazy Roots.
+	///
+	/// Unresolved root paths, queued by [`Dowser::with_lazy_paths`].
+	lazy: Option<LazyPaths>,
+
+	/// # Defer Roots?
+	///
+	/// If `true`, roots are queued as-are and resolved during the crawl.
+	defer_roots: bool,
+
+	/// # Deferred Roots.
+	///
+	/// Unresolved root paths, queued while deferring.
+	deferred: Vec<PathBuf>,
 
 	/// # Encountered Hashes.
 	///
 	/// This is used to prevent parsing the same file/directory twice.
 	seen: HashSet<u64, NoHash>,
+
+	/// # Shared Seen Set.
+	///
+	/// If present, this is consulted (and updated) alongside `seen`, allowing
+	/// multiple instances to divvy up the work.
+	shared: Option<SharedSeen>,
+
+	/// # Minimum Depth.
+	///
+	/// Files shallower than this are not yielded.
+	min_depth: usize,
+
+	/// # Maximum Depth.
+	///
+	/// Files deeper than this are not yielded, nor are directories that
+	/// could only contain such files read.
+	max_depth: usize,
+
+	/// # Depth Ceiling.
+	///
+	/// Directories deeper than this are never read.
+	depth_ceiling: usize,
+
+	/// # Too Deep.
+	///
+	/// Directories skipped for exceeding the depth ceiling.
+	too_deep: Vec<PathBuf>,
+
+	/// # Follow Symlinks?
+	symlinks: bool,
+
+	/// # Maximum Symlink Hops.
+	///
+	/// If present, symlinks chaining through more links than this are not
+	/// followed.
+	symlink_hops: Option<u8>,
+
+	/// # Subdirectory Limit.
+	///
+	/// The maximum number of subdirectories to queue per directory, if any.
+	dir_limit: Option<usize>,
+
+	/// # Entry Limit.
+	///
+	/// The maximum number of entries to read per directory, if any.
+	entry_limit: Option<usize>,
+
+	/// # Truncated Directories.
+	///
+	/// Directories with entries left unread because of the entry limit.
+	truncated: Vec<PathBuf>,
+
+	/// # Changed Types.
+	///
+	/// Directories that turned out not to be directories by the time they
+	/// were read.
+	type_changed: Vec<PathBuf>,
+
+	/// # Directory Source.
+	///
+	/// If present, directories are read from this instead of the
+	/// filesystem.
+	source: Option<Source>,
+
+	/// # Prefetch Directory Entries?
+	prefetch: bool,
+
+	/// # Pre-Allocate From Link Counts?
+	nlink_hint: bool,
+
+	/// # Retry Policy.
+	retry: Retry,
+
+	/// # Failed Reads.
+	///
+	/// Directories that could not be read despite retrying.
+	failed_reads: Vec<(PathBuf, Arc<std::io::Error>)>,
+
+	/// # Time Budget.
+	///
+	/// If present, the crawl stops once this much time has elapsed since the
+	/// first directory read.
+	time_budget: Option<Duration>,
+
+	/// # Start Time.
+	started: Option<Instant>,
+
+	/// # Required Root Kind.
+	///
+	/// If `Some(true)`, roots must be directories; if `Some(false)`, files.
+	root_dirs: Option<bool>,
+
+	/// # Root Filter.
+	///
+	/// If present, roots must pass this test to be queued.
+	root_filter: Option<Callback<Path>>,
+
+	/// # Confinement Roots.
+	///
+	/// If present, all paths must live under one of these.
+	confine: Option<Vec<PathBuf>>,
+
+	/// # Permission Policy.
+	permission_policy: PermissionPolicy,
+
+	/// # Permission Denied Count.
+	///
+	/// The number of unreadable directories encountered, if counting.
+	permission_denied: usize,
+
+	/// # Directory Filter.
+	///
+	/// If present, directories must pass this test to be reported by
+	/// directory-yielding methods.
+	dir_filter: Option<Callback<Path>>,
+
+	/// # Pruned Directory Names.
+	///
+	/// Directories with any of these names are not crawled.
+	dir_names: Vec<OsString>,
+
+	/// # Ignore Files.
+	ignores: Ignores,
+
+	/// # Excluded Symlinks.
+	///
+	/// The hashes of specific symlinks — not their targets — that should
+	/// not be followed.
+	link_excludes: HashSet<u64, NoHash>,
+
+	/// # File Filter.
+	///
+	/// If present, files must pass this test to be yielded.
+	filter: Option<Callback<Path>>,
+
+	/// # Base Directory.
+	///
+	/// If present, relative paths are resolved against this rather than the
+	/// current working directory.
+	base: Option<PathBuf>,
+
+	/// # List File Extensions.
+	///
+	/// Files with these extensions are treated as path lists, and expanded
+	/// rather than yielded.
+	list_exts: Vec<Extension>,
+
+	/// # Bundle Directory Extensions.
+	///
+	/// Directories with these extensions are yielded like files rather than
+	/// crawled.
+	bundle_exts: Vec<Extension>,
+
+	/// # Symlink Report.
+	///
+	/// If enabled, this holds the `(link, target)` pairs for every symlink
+	/// followed during the crawl.
+	symlink_report: Option<Vec<(PathBuf, PathBuf)>>,
+
+	/// # Broken Symlink Report.
+	///
+	/// If enabled, this holds the `(link, error)` pairs for every symlink
+	/// that could not be resolved during the crawl.
+	broken_symlinks: Option<Vec<(PathBuf, Arc<std::io::Error>)>>,
+
+	#[cfg(feature = "archives")]
+	/// # Archive Kinds.
+	///
+	/// The archive formats to crawl into, if any.
+	archives: crate::ArchiveKinds,
+
+	#[cfg(feature = "rayon")]
+	/// # Thread Pool.
+	///
+	/// If present, parallel iteration runs here instead of the current
+	/// (or global) pool.
+	pool: Option<Arc<rayon::ThreadPool>>,
+
+	#[cfg(feature = "rayon")]
+	/// # Parallel Baseline.
+	///
+	/// The hashes seen before a parallel crawl began — roots and exclusions
+	/// — shared read-only by all of the workers so each can start with an
+	/// empty `seen`.
+	baseline: Option<Arc<HashSet<u64, NoHash>>>,
+
+	#[cfg(feature = "regex")]
+	/// # Exclusion Patterns.
+	exclude: Option<regex::bytes::RegexSet>,
 }
 
 impl Default for Dowser {
 	#[inline]
-	fn default() -> Self {
+	fn default() -> Self { Self::with_capacity(Self::DEFAULT_SEEN_CAPACITY) }
+}
+
+impl Dowser {
+	/// # Default Queue Capacity.
+	///
+	/// The number of file and directory paths — each — a new [`Dowser`]
+	/// has room for before reallocating. These queues are drained as the
+	/// crawl progresses, so rarely need to grow very large.
+	pub const DEFAULT_QUEUE_CAPACITY: usize = 8;
+
+	/// # Default Seen Capacity.
+	///
+	/// The number of unique paths — files, directories, and exclusions — a
+	/// new [`Dowser`] has room for before its deduplication set needs to
+	/// reallocate. Unlike the queues, this set grows for the whole crawl.
+	///
+	/// See [`Dowser::with_capacity`] to pick a different starting point.
+	pub const DEFAULT_SEEN_CAPACITY: usize = 4096;
+
+	/// # Default Depth Ceiling.
+	///
+	/// The maximum directory depth a new [`Dowser`] will descend to. See
+	/// [`Dowser::with_depth_ceiling`] for details.
+	pub const DEFAULT_DEPTH_CEILING: usize = 1024;
+
+	/// # Size Histogram Buckets.
+	///
+	/// The number of buckets returned by [`Dowser::size_histogram`].
+	pub const SIZE_BUCKETS: usize = 13;
+
+	#[must_use]
+	/// # With Capacity.
+	///
+	/// Create a new, empty instance — like [`Dowser::default`] — with room
+	/// for at least `seen` unique paths before reallocating.
+	///
+	/// This is worth tuning if you have a rough idea of how big the crawl
+	/// will be: larger trees can avoid repeated rehashing, while many small
+	/// crawls can avoid over-allocating.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // We're expecting a big one!
+	/// let files: Vec<PathBuf> = Dowser::with_capacity(Dowser::DEFAULT_SEEN_CAPACITY * 64)
+	///     .with_path("/")
+	///     .collect();
+	/// ```
+	pub fn with_capacity(seen: usize) -> Self {
 		Self {
-			files: Vec::with_capacity(8),
-			dirs: Vec::with_capacity(8),
-			seen: HashSet::with_capacity_and_hasher(4096, NoHash::default()),
+			files: Vec::with_capacity(Self::DEFAULT_QUEUE_CAPACITY),
+			file_depths: None,
+			dirs: VecDeque::with_capacity(Self::DEFAULT_QUEUE_CAPACITY),
+			order: Order::DepthFirst,
+			lazy: None,
+			defer_roots: false,
+			deferred: Vec::new(),
+			seen: HashSet::with_capacity_and_hasher(seen, NoHash::default()),
+			shared: None,
+			min_depth: 0,
+			max_depth: usize::MAX,
+			depth_ceiling: Self::DEFAULT_DEPTH_CEILING,
+			too_deep: Vec::new(),
+			symlinks: true,
+			symlink_hops: None,
+			dir_limit: None,
+			entry_limit: None,
+			truncated: Vec::new(),
+			type_changed: Vec::new(),
+			source: None,
+			prefetch: false,
+			nlink_hint: false,
+			retry: Retry::NONE,
+			failed_reads: Vec::new(),
+			time_budget: None,
+			started: None,
+			root_dirs: None,
+			root_filter: None,
+			confine: None,
+			permission_policy: PermissionPolicy::Skip,
+			permission_denied: 0,
+			dir_filter: None,
+			dir_names: Vec::new(),
+			ignores: Ignores::default(),
+			link_excludes: HashSet::default(),
+			filter: None,
+			base: None,
+			list_exts: Vec::new(),
+			bundle_exts: Vec::new(),
+			symlink_report: None,
+			broken_symlinks: None,
+			#[cfg(feature = "archives")]
+			archives: crate::ArchiveKinds::NONE,
+			#[cfg(feature = "rayon")]
+			pool: None,
+			#[cfg(feature = "rayon")]
+			baseline: None,
+			#[cfg(feature = "regex")]
+			exclude: None,
 		}
 	}
 }
@@ -92,12 +550,7 @@ impl From<&[PathBuf]> for Dowser {
 	fn from(src: &[PathBuf]) -> Self {
 		let mut out = Self::default();
 
-		for e in src.iter().filter_map(Entry::from_path) {
-			if out.seen.insert(e.hash) {
-				if e.is_dir { out.dirs.push(e.path); }
-				else { out.files.push(e.path); }
-			}
-		}
+		for e in src.iter().filter_map(Entry::from_path) { out.push_root(e); }
 
 		out
 	}
@@ -107,12 +560,7 @@ impl From<Vec<PathBuf>> for Dowser {
 	fn from(src: Vec<PathBuf>) -> Self {
 		let mut out = Self::default();
 
-		for e in src.into_iter().filter_map(Entry::from_path) {
-			if out.seen.insert(e.hash) {
-				if e.is_dir { out.dirs.push(e.path); }
-				else { out.files.push(e.path); }
-			}
-		}
+		for e in src.into_iter().filter_map(Entry::from_path) { out.push_root(e); }
 
 		out
 	}
@@ -134,20 +582,9 @@ impl Iterator for Dowser {
 				return Some(p);
 			}
 
-			if let Some(p) = self.dirs.pop() {
-				if let Ok(rd) = std::fs::read_dir(p) {
-					for e in rd {
-						if let Some(e) = Entry::from_entry(e) {
-							if self.seen.insert(e.hash) {
-								if e.is_dir { self.dirs.push(e.path); }
-								else { self.files.push(e.path); }
-							}
-						}
-					}
-				}
-			}
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
 			// We're out of things to do!
-			else { break; }
+			else if self.files.is_empty() { break; }
 		}
 
 		None
@@ -161,7 +598,9 @@ impl Iterator for Dowser {
 	fn size_hint(&self) -> (usize, Option<usize>) {
 		let lower = self.files.len();
 		let upper =
-			if self.dirs.is_empty() { Some(lower) }
+			if self.dirs.is_empty() && self.deferred.is_empty() && self.lazy.is_none() {
+				Some(lower)
+			}
 			else { None };
 
 		(lower, upper)
@@ -189,16 +628,81 @@ impl Dowser {
 	/// ```
 	pub fn with_path<P>(mut self, path: P) -> Self
 	where P: AsRef<Path> {
-		if let Some(e) = Entry::from_path(path) {
-			if self.seen.insert(e.hash) {
-				if e.is_dir { self.dirs.push(e.path); }
-				else { self.files.push(e.path); }
-			}
-		}
+		self.queue_path(path.as_ref());
+		self
+	}
 
+	#[must_use]
+	/// # With Path (Owned).
+	///
+	/// This works just like [`Dowser::with_path`], but takes ownership of
+	/// the path so it can be queued as-is, without being copied, when
+	/// [deferring](Dowser::defer_roots) root resolution.
+	///
+	/// (Otherwise roots are resolved immediately, so there's no copy to save
+	/// either way.)
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let roots: Vec<PathBuf> = Vec::new(); // Imagine lots of roots…
+	/// let crawl = roots.into_iter().fold(
+	///     Dowser::default().defer_roots(),
+	///     Dowser::with_pathbuf,
+	/// );
+	/// ```
+	pub fn with_pathbuf(mut self, path: PathBuf) -> Self {
+		if self.defer_roots {
+			let path = match rebase(self.base.as_deref(), &path) {
+				Cow::Owned(p) => p,
+				Cow::Borrowed(_) => path,
+			};
+			self.deferred.push(path);
+		}
+		else { self.push_path(&path); }
 		self
 	}
 
+	/// # Try With Path.
+	///
+	/// Same as [`Dowser::with_path`], except problems are reported rather
+	/// than silently ignored.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let crawl = Dowser::default()
+	///     .require_dir_roots()
+	///     .try_with_path("/my/project")
+	///     .expect("Projects must be directories!");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the path cannot be canonicalized, or if
+	/// it is the wrong kind of thing per [`Dowser::require_dir_roots`] or
+	/// [`Dowser::require_file_roots`]. Roots rejected by
+	/// [`Dowser::with_root_filter`] result in an error of kind
+	/// [`InvalidInput`](ErrorKind::InvalidInput).
+	pub fn try_with_path<P>(mut self, path: P) -> Result<Self, std::io::Error>
+	where P: AsRef<Path> {
+		let path = std::fs::canonicalize(rebase(self.base.as_deref(), path.as_ref()))?;
+		let is_dir = path.is_dir();
+		if let Some(kind) = self.root_kind_error(is_dir) { return Err(kind.into()); }
+		if self.root_filter.as_ref().is_some_and(|cb| ! cb.call(&path)) {
+			return Err(ErrorKind::InvalidInput.into());
+		}
+
+		let hash = Entry::hash_path(&path, is_dir);
+		self.push_root(Entry { path, link: None, is_dir, hash });
+		Ok(self)
+	}
+
 	#[inline]
 	#[must_use]
 	/// # With Paths.
@@ -226,20 +730,33 @@ impl Dowser {
 		assert!(! is_singular_path(&paths), "Dowser::with_paths requires an Iterator of paths, not a direct Path/PathBuf object.");
 		paths.into_iter().fold(self, Self::with_path)
 	}
-}
 
-impl Dowser {
-	/// # Load Paths From File.
+	#[must_use]
+	/// # With Lazy Paths.
 	///
-	/// Queue up multiple file and/or directory paths from a text file, one
-	/// entry per line.
+	/// Queue up multiple file and/or directory paths, like
+	/// [`Dowser::with_paths`], except they won't be resolved until the crawl
+	/// actually needs them.
 	///
-	/// Lines are trimmed and ignored if empty, but otherwise resolved the
-	/// same as if passed directly to methods like [`Dowser::with_path`], i.e.
-	/// relative to the current working directory (not the text file itself).
+	/// Each time the crawler runs out of directories to read, it pulls roots
+	/// from the iterator — canonicalizing and deduplicating them as usual —
+	/// until it has a new directory to work on (or the iterator is
+	/// exhausted). This spreads the cost of resolution across the crawl
+	/// instead of front-loading it, which can help when the root list is
+	/// itself large or expensive to generate.
 	///
-	/// For that reason, it is recommended that all paths stored in text files
-	/// be absolute to avoid any ambiguity.
+	/// Lazy roots are queued _after_ all regular roots, and are not
+	/// reflected in [`Dowser::estimate`], [`Dowser::pending_dirs`], etc.,
+	/// until pulled. Some consumers — [`Dowser::deepest`], the per-root
+	/// methods like [`Dowser::cross_root_duplicates`] — need every root up
+	/// front, and will resolve them all at once.
+	///
+	/// Calling this more than once chains the iterators together.
+	///
+	/// Note: the iterator itself can't be cloned, so if a `Dowser` is cloned
+	/// before all its lazy roots have been pulled, the clones will _share_
+	/// the remainder, each root going to whichever instance gets to it
+	/// first.
 	///
 	/// ## Examples
 	///
@@ -247,47 +764,39 @@ impl Dowser {
 	/// use dowser::Dowser;
 	/// use std::path::PathBuf;
 	///
-	/// // Read the paths from list.txt.
-	/// let mut crawler = Dowser::default();
-	/// crawler.read_paths_from_file("list.txt").unwrap();
-	///
-	/// // Crunch into a vec.
-	/// let files: Vec::<PathBuf> = crawler.collect();
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_lazy_paths((0..10_000).map(|i| format!("/srv/users/{i}")))
+	///     .collect();
 	/// ```
-	///
-	/// ## Errors
-	///
-	/// This method will bubble up any errors encountered while trying to read
-	/// the text file.
-	pub fn read_paths_from_file<P: AsRef<Path>>(&mut self, src: P)
-	-> Result<(), std::io::Error> {
-		let raw = std::fs::read_to_string(src)?;
-		for line in raw.lines() {
-			let line = line.trim();
-			if ! line.is_empty() {
-				if let Some(e) = Entry::from_path(line) {
-					if self.seen.insert(e.hash) {
-						if e.is_dir { self.dirs.push(e.path); }
-						else { self.files.push(e.path); }
-					}
-				}
-			}
-		}
-
-		Ok(())
+	pub fn with_lazy_paths<P, I>(mut self, paths: I) -> Self
+	where
+		P: AsRef<Path>,
+		I: IntoIterator<Item=P>,
+		I::IntoIter: Send + 'static {
+		let paths = paths.into_iter().map(|p| p.as_ref().to_path_buf());
+		self.lazy = Some(match self.lazy.take() {
+			Some(prev) => LazyPaths(Arc::new(Mutex::new(
+				std::iter::from_fn(move || prev.next()).chain(paths)
+			))),
+			None => LazyPaths(Arc::new(Mutex::new(paths))),
+		});
+		self
 	}
-}
 
-impl Dowser {
 	#[must_use]
-	#[inline]
-	/// # Without Path.
+	/// # With (Pre-)Resolved Path.
 	///
-	/// This will prevent the provided directory or file from being crawled or
-	/// included in the output.
+	/// Queue up a single file or directory path _without_ canonicalizing it
+	/// or checking what it is first.
 	///
-	/// Note: without-path(s) should be specified before with-path(s), just in
-	/// case the sets overlap.
+	/// This is an escape hatch for callers who have already resolved their
+	/// paths — from a cache, say — and would rather not pay for the
+	/// redundant syscalls.
+	///
+	/// The path is trusted completely, so it is up to you to make sure it
+	/// is canonical and that `is_dir` is accurate. If not, deduplication
+	/// will suffer and you may wind up with garbage in the output. When in
+	/// doubt, use [`Dowser::with_path`] instead.
 	///
 	/// ## Examples
 	///
@@ -296,29 +805,30 @@ impl Dowser {
 	/// use std::path::PathBuf;
 	///
 	/// let files: Vec<PathBuf> = Dowser::default()
-	///     .without_path("/my/dir/ignore")
-	///     .with_path("/my/dir")
+	///     .with_resolved(PathBuf::from("/usr/share/man"), true)
 	///     .collect();
 	/// ```
-	pub fn without_path<P>(mut self, path: P) -> Self
-	where P: AsRef<Path> {
-		if let Ok(p) = std::fs::canonicalize(path) {
-			let hash = Entry::hash_path(&p);
-			self.seen.insert(hash);
-		}
-
+	pub fn with_resolved(mut self, path: PathBuf, is_dir: bool) -> Self {
+		let hash = Entry::hash_path(&path, is_dir);
+		self.push_root(Entry { path, link: None, is_dir, hash });
 		self
 	}
 
 	#[must_use]
-	#[inline]
-	/// # Without Paths.
+	/// # With Base Directory.
 	///
-	/// This will prevent the provided directories or files from being crawled
-	/// or included in the output.
+	/// Resolve any _relative_ paths passed to subsequent calls like
+	/// [`Dowser::with_path`], [`Dowser::without_path`], or
+	/// [`Dowser::read_paths_from_file`] against `base` instead of the
+	/// current working directory. Absolute paths are unaffected.
 	///
-	/// Note: without-path(s) should be specified before with-path(s), just in
-	/// case the sets overlap.
+	/// This is handy when the "working directory" for a given crawl differs
+	/// from the process's, as there is no need to juggle
+	/// [`std::env::set_current_dir`].
+	///
+	/// The base itself is canonicalized once, up front. Note that paths
+	/// added _before_ calling this method will already have been resolved
+	/// against the current working directory.
 	///
 	/// ## Examples
 	///
@@ -326,266 +836,6353 @@ impl Dowser {
 	/// use dowser::Dowser;
 	/// use std::path::PathBuf;
 	///
+	/// // Crawl /srv/tenant/uploads and /usr/share/man.
 	/// let files: Vec<PathBuf> = Dowser::default()
-	///     .without_paths(&["/my/dir/ignore"])
-	///     .with_path("/my/dir")
+	///     .with_base_dir(PathBuf::from("/srv/tenant"))
+	///     .with_path("uploads")
+	///     .with_path("/usr/share/man")
 	///     .collect();
 	/// ```
+	pub fn with_base_dir(mut self, base: PathBuf) -> Self {
+		self.base.replace(std::fs::canonicalize(&base).unwrap_or(base));
+		self
+	}
+
+	#[must_use]
+	/// # Normalize Roots.
 	///
-	/// ## Panics
+	/// Drop any queued directory root that lives inside another queued
+	/// directory root, leaving it to be discovered naturally — as a regular
+	/// subdirectory — during the crawl.
 	///
-	/// This will panic if you try to pass a single `Path` or `PathBuf` object
-	/// directly to this method (instead of a collection of same). Use
-	/// [`Dowser::without_path`] to add such an object directly.
-	pub fn without_paths<P, I>(mut self, paths: I) -> Self
-	where P: AsRef<Path>, I: IntoIterator<Item=P> {
-		assert!(! is_singular_path(&paths), "Dowser::without_paths requires an Iterator of paths, not a direct Path/PathBuf object.");
+	/// Overlapping roots are never read twice either way, but they are
+	/// otherwise treated as roots: their depth starts at zero, and they are
+	/// exempt from things like [`Dowser::without_dir_names`]. Normalizing
+	/// makes the crawl behave as if only the outermost roots had been
+	/// given, which is usually what you want for glob-expanded arguments and
+	/// the like.
+	///
+	/// Note: this should be called after all paths have been added, but
+	/// before crawling.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // The node_modules root is dropped, and then pruned.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .without_dir_names(&["node_modules"])
+	///     .with_paths(["/my/project", "/my/project/node_modules"])
+	///     .normalize_roots()
+	///     .collect();
+	/// ```
+	pub fn normalize_roots(mut self) -> Self {
+		self.resolve_lazy();
+		if 1 < self.dirs.len() {
+			// Sorting (a copy) puts ancestors directly ahead of their
+			// descendants.
+			let mut sorted: Vec<&Path> = self.dirs.iter().map(|(p, _)| p.as_path()).collect();
+			sorted.sort_unstable();
+
+			let mut nested: HashSet<u64, NoHash> = HashSet::default();
+			let mut last: Option<&Path> = None;
+			for p in sorted {
+				if last.is_some_and(|l| p.starts_with(l)) {
+					nested.insert(Entry::hash_path(p, true));
+				}
+				else { last = Some(p); }
+			}
+
+			// Drop the nested ones, leaving the rest in their original order,
+			// and forget them so they can be found again.
+			if ! nested.is_empty() {
+				self.dirs.retain(|(p, _)| ! nested.contains(&Entry::hash_path(p, true)));
+				for hash in nested { self.seen.remove(&hash); }
+			}
+		}
+
+		self
+	}
+}
+
+impl Dowser {
+	#[must_use]
+	#[inline]
+	/// # With Minimum Depth.
+	///
+	/// Skip any files shallower than `depth`, relative to the root that led
+	/// to them. Files passed directly to [`Dowser::with_path`] and kin have a
+	/// depth of zero; files inside those directories have a depth of one;
+	/// and so on.
+	///
+	/// Directories are still traversed regardless of depth in order to reach
+	/// the deeper files within.
+	///
+	/// Combine with [`Dowser::with_max_depth`] to yield a band of depths. If
+	/// the minimum exceeds the maximum, nothing will be yielded.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Skip /my/project/README.md, etc.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_min_depth(2)
+	///     .with_path("/my/project")
+	///     .collect();
+	/// ```
+	pub fn with_min_depth(mut self, depth: usize) -> Self {
+		// Any files already buffered are roots, and too shallow.
+		if 0 < depth { self.files.clear(); }
+		self.min_depth = depth;
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Maximum Depth.
+	///
+	/// Skip any files deeper than `depth`, relative to the root that led to
+	/// them, using the same counting as [`Dowser::with_min_depth`].
+	///
+	/// Unlike the minimum, this also prunes the crawl: directories whose
+	/// contents would all be too deep are not read at all. A maximum of zero
+	/// therefore yields only the file roots.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Only /my/project/src/*.rs and /my/project/src/*/*.rs.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_min_depth(1)
+	///     .with_max_depth(2)
+	///     .with_path("/my/project/src")
+	///     .collect();
+	/// ```
+	pub const fn with_max_depth(mut self, depth: usize) -> Self {
+		self.max_depth = depth;
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Depth Ceiling.
+	///
+	/// Directories more than `depth` levels below their root will be skipped
+	/// rather than read, and recorded for later review via
+	/// [`Dowser::too_deep`].
+	///
+	/// This is a safety backstop rather than a filter: it is always on, with
+	/// a default of [`Dowser::DEFAULT_DEPTH_CEILING`] — far deeper than any
+	/// sane hierarchy — to keep maliciously-constructed trees (e.g. symlink
+	/// chains generating endless unique paths) from running away with the
+	/// crawl.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let mut crawl = Dowser::default()
+	///     .with_depth_ceiling(64)
+	///     .with_path("/srv/uploads");
+	///
+	/// crawl.by_ref().for_each(drop);
+	/// for dir in crawl.too_deep() {
+	///     eprintln!("Suspiciously deep: {}", dir.display());
+	/// }
+	/// ```
+	pub const fn with_depth_ceiling(mut self, depth: usize) -> Self {
+		self.depth_ceiling = depth;
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Traversal Order.
+	///
+	/// Set the order in which directories are read: [`Order::DepthFirst`]
+	/// (the default) or [`Order::BreadthFirst`].
+	///
+	/// Since files are yielded as their directories are read, breadth-first
+	/// traversal guarantees that the files in shallower directories are
+	/// yielded before those in deeper ones, handy for processing parents
+	/// before children. Depth-first traversal, on the other hand, keeps the
+	/// queue — and memory usage — smaller on wide trees.
+	///
+	/// Note: this does not affect the order of entries _within_ a
+	/// directory, which is up to the filesystem. If you need fully
+	/// deterministic results, sort them.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, Order};
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_order(Order::BreadthFirst)
+	///     .with_path("/my/project")
+	///     .collect();
+	/// ```
+	pub const fn with_order(mut self, order: Order) -> Self {
+		self.order = order;
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Follow Symlinks.
+	///
+	/// Set whether or not symlinks encountered during the crawl should be
+	/// followed. (They are by default.)
+	///
+	/// When disabled, symlinks found _inside_ directories are skipped
+	/// entirely — neither yielded nor traversed. Paths passed directly to
+	/// [`Dowser::with_path`] and kin are always resolved, symlink or not.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// # let follow = false;
+	/// // Useful for runtime settings, like a CLI flag.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_follow_symlinks(follow)
+	///     .with_path("/usr/share")
+	///     .collect();
+	/// ```
+	pub const fn with_follow_symlinks(mut self, follow: bool) -> Self {
+		self.symlinks = follow;
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Without Symlinks.
+	///
+	/// This is a convenience alias for
+	/// [`Dowser::with_follow_symlinks(false)`](Dowser::with_follow_symlinks).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .without_symlinks()
+	///     .with_path("/usr/share")
+	///     .collect();
+	/// ```
+	pub const fn without_symlinks(self) -> Self { self.with_follow_symlinks(false) }
+
+	#[must_use]
+	#[inline]
+	/// # With Maximum Symlink Hops.
+	///
+	/// Refuse to follow symlinks that chain through more than `max` links
+	/// — counting the first — before reaching something real. A value of
+	/// zero rejects _all_ symlinks, though [`Dowser::without_symlinks`] is
+	/// cheaper for that.
+	///
+	/// Chains are walked manually, one `readlink` at a time, prior to the
+	/// usual canonicalization. Only the final path component is considered
+	/// at each step, so links _within_ a target's parent directories do not
+	/// count toward the total.
+	///
+	/// Rejected links are skipped, but can be retrieved afterward via
+	/// [`Dowser::broken_symlinks`] if [`Dowser::with_broken_symlink_report`]
+	/// is enabled.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let mut crawl = Dowser::default()
+	///     .with_max_symlink_hops(2)
+	///     .with_broken_symlink_report()
+	///     .with_path("/srv/uploads");
+	///
+	/// crawl.by_ref().for_each(drop);
+	/// for (link, err) in crawl.broken_symlinks() {
+	///     eprintln!("Rejected: {} ({err})", link.display());
+	/// }
+	/// ```
+	pub const fn with_max_symlink_hops(mut self, max: u8) -> Self {
+		self.symlink_hops = Some(max);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Per-Level Directory Limit.
+	///
+	/// Only queue up the first `limit` subdirectories — sorted by (canonical)
+	/// path for reproducibility — of each directory read, skipping the rest.
+	/// Files are unaffected.
+	///
+	/// This is useful for taking a representative sample of extremely wide
+	/// directory trees without traversing the whole thing.
+	///
+	/// Note: the limit applies to _discovered_ subdirectories only; roots
+	/// are always queued.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Only descend into the first ten subdirectories of each directory.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_per_level_dir_limit(10)
+	///     .with_path("/usr/share")
+	///     .collect();
+	/// ```
+	pub const fn with_per_level_dir_limit(mut self, limit: usize) -> Self {
+		self.dir_limit = Some(limit);
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # With Max Entries Per Directory.
+	///
+	/// Stop reading any given directory after `limit` entries — files,
+	/// subdirectories, and anything else — leaving the rest unread.
+	///
+	/// This bounds the work spent on pathologically wide directories, like
+	/// a mail spool with millions of messages. Directories that were cut
+	/// short can be retrieved afterwards via [`Dowser::truncated_dirs`].
+	///
+	/// Note: entries are counted in whatever order the filesystem hands them
+	/// over, which is arbitrary, so _which_ entries are kept may change
+	/// from run-to-run.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let mut crawl = Dowser::default()
+	///     .with_max_entries_per_dir(10_000)
+	///     .with_path("/var/spool");
+	///
+	/// let files: Vec<_> = crawl.by_ref().collect();
+	/// for dir in crawl.truncated_dirs() {
+	///     eprintln!("Partially read: {}", dir.display());
+	/// }
+	/// ```
+	pub const fn with_max_entries_per_dir(mut self, limit: usize) -> Self {
+		self.entry_limit = Some(limit);
+		self
+	}
+
+	#[must_use]
+	/// # With Prefetch.
+	///
+	/// When enabled, the entire contents of each directory are read into
+	/// memory up front, before any of the entries are resolved or queued.
+	///
+	/// This batches the I/O and closes each directory handle as quickly as
+	/// possible, which can help on high-latency (e.g. network) filesystems
+	/// or when file descriptors are in short supply. The cost is a little
+	/// extra memory for the duration of each read.
+	///
+	/// The results are the same either way.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_prefetch()
+	///     .with_path("/mnt/nfs")
+	///     .collect();
+	/// ```
+	pub const fn with_prefetch(mut self) -> Self {
+		self.prefetch = true;
+		self
+	}
+
+	#[must_use]
+	/// # With Link-Count Hint.
+	///
+	/// When enabled, the hard link count of each directory root is used to
+	/// guess how many subdirectories it holds — on most Unix filesystems,
+	/// `nlink - 2` — and the internal queue and deduplication set are grown
+	/// accordingly, up front, to cut down on reallocations and rehashing
+	/// during the crawl.
+	///
+	/// This is a best-effort heuristic only. Some filesystems (btrfs, for
+	/// one) always report a link count of one for directories, in which case
+	/// nothing happens. The results are the same either way.
+	///
+	/// On non-Unix platforms, this does nothing.
+	///
+	/// Note: this should be specified before any with-path(s).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_nlink_hint()
+	///     .with_path("/var/lib")
+	///     .collect();
+	/// ```
+	pub const fn with_nlink_hint(mut self) -> Self {
+		self.nlink_hint = true;
+		self
+	}
+
+	#[must_use]
+	/// # With Source.
+	///
+	/// Read directories from a custom [`DirSource`] instead of the
+	/// filesystem. This is primarily useful for testing crawl logic against
+	/// deterministic, in-memory trees, but can also be used to crawl
+	/// non-filesystem hierarchies.
+	///
+	/// See [`DirSource`] for the details and caveats. Note that
+	/// filesystem-specific settings like [`Dowser::with_prefetch`] and
+	/// [`Dowser::without_symlinks`] have no effect on custom sources.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{DirSource, Dowser};
+	/// use std::{
+	///     io::Result,
+	///     path::{Path, PathBuf},
+	/// };
+	///
+	/// /// # An Empty Tree.
+	/// struct Empty;
+	///
+	/// impl DirSource for Empty {
+	///     fn read_dir(&self, _path: &Path) -> Result<Vec<(PathBuf, bool)>> {
+	///         Ok(Vec::new())
+	///     }
+	/// }
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_source(Empty)
+	///     .with_resolved(PathBuf::from("/"), true)
+	///     .collect();
+	/// assert!(files.is_empty());
+	/// ```
+	pub fn with_source<S: DirSource + 'static>(mut self, src: S) -> Self {
+		self.source.replace(Source(Arc::new(src)));
+		self
+	}
+
+	#[must_use]
+	/// # With Time Budget.
+	///
+	/// Stop crawling once the given amount of wall-clock time has elapsed,
+	/// as measured from the first directory read.
+	///
+	/// Once the budget is exceeded, any pending directories are abandoned
+	/// and the crawl winds down naturally, yielding whatever files were
+	/// already found. No error is raised; partial results are the point.
+	///
+	/// Note that the clock is only checked between directory reads, so a
+	/// single slow directory can push things a little over budget.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::{
+	///     path::PathBuf,
+	///     time::Duration,
+	/// };
+	///
+	/// // Find as much as possible in two seconds.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_time_budget(Duration::from_secs(2))
+	///     .with_path("/")
+	///     .collect();
+	/// ```
+	pub const fn with_time_budget(mut self, budget: Duration) -> Self {
+		self.time_budget = Some(budget);
+		self
+	}
+
+	#[must_use]
+	/// # With Retry.
+	///
+	/// Retry directory reads and symlink resolution up to `attempts` more
+	/// times — waiting `backoff` in between — when they fail for reasons
+	/// that might be transient, as can happen on flaky network mounts.
+	///
+	/// Only interruptions, timeouts, busy or stale handles, and generic I/O
+	/// (`EIO`) errors are retried. Things like missing files or permission
+	/// problems are not.
+	///
+	/// Directories that still can't be read after all that are skipped,
+	/// as usual, but can be retrieved afterwards via
+	/// [`Dowser::failed_reads`]. (Symlinks that can't be resolved wind up in
+	/// the [broken symlinks](Dowser::with_broken_symlink_report) report, if
+	/// enabled.)
+	///
+	/// Note: roots are resolved as they're added, without retrying.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::{
+	///     path::PathBuf,
+	///     time::Duration,
+	/// };
+	///
+	/// let mut crawl = Dowser::default()
+	///     .with_retry(3, Duration::from_millis(250))
+	///     .with_path("/mnt/nfs");
+	///
+	/// let files: Vec<PathBuf> = crawl.by_ref().collect();
+	/// for (dir, err) in crawl.failed_reads() {
+	///     eprintln!("Unable to read {}: {err}", dir.display());
+	/// }
+	/// ```
+	pub const fn with_retry(mut self, attempts: u8, backoff: Duration) -> Self {
+		self.retry = Retry::new(attempts, backoff);
+		self
+	}
+
+	#[must_use]
+	/// # On Permission Denied.
+	///
+	/// Set the policy for dealing with directories that cannot be read due
+	/// to insufficient permissions:
+	///
+	/// | Policy | Effect |
+	/// | ------ | ------ |
+	/// | [`PermissionPolicy::Skip`] | Skip them silently (the default). |
+	/// | [`PermissionPolicy::Count`] | Skip them, but keep count. |
+	/// | [`PermissionPolicy::Abort`] | Stop the crawl at the first one. |
+	///
+	/// In the latter two cases, the total can be retrieved afterwards from
+	/// [`Dowser::permission_denied`], so you'll want to hold onto the
+	/// instance with something like [`Iterator::by_ref`]. Other kinds of
+	/// read errors are always skipped silently.
+	///
+	/// Note that aborting only stops _future_ reads; any files already
+	/// yielded will have been yielded.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, PermissionPolicy};
+	/// use std::path::PathBuf;
+	///
+	/// let mut crawl = Dowser::default()
+	///     .on_permission_denied(PermissionPolicy::Abort)
+	///     .with_path("/srv/shared");
+	///
+	/// let files: Vec<PathBuf> = crawl.by_ref().collect();
+	/// if crawl.permission_denied() != 0 {
+	///     eprintln!("The crawl was incomplete!");
+	/// }
+	/// ```
+	pub const fn on_permission_denied(mut self, policy: PermissionPolicy) -> Self {
+		self.permission_policy = policy;
+		self
+	}
+
+	#[must_use]
+	/// # Require Directory Roots.
+	///
+	/// Only queue up roots — paths passed to [`Dowser::with_path`] and kin —
+	/// that are directories, ignoring any files. Use
+	/// [`Dowser::try_with_path`] if you'd rather know about it.
+	///
+	/// This is equivalent to `with_root_filter(Path::is_dir)`, but cheaper,
+	/// as the answer is already known. Calling this replaces any previous
+	/// [`Dowser::require_file_roots`].
+	///
+	/// Note: like the `without_*` methods, this should be called _before_ any
+	/// `with_*` methods, otherwise it won't have anything to filter.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // /etc/hosts will be ignored.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .require_dir_roots()
+	///     .with_paths(["/usr/share", "/etc/hosts"])
+	///     .collect();
+	/// ```
+	pub const fn require_dir_roots(mut self) -> Self {
+		self.root_dirs = Some(true);
+		self
+	}
+
+	#[must_use]
+	/// # Require File Roots.
+	///
+	/// Only queue up roots — paths passed to [`Dowser::with_path`] and kin —
+	/// that are files, ignoring any directories. Use
+	/// [`Dowser::try_with_path`] if you'd rather know about it.
+	///
+	/// This is the opposite of [`Dowser::require_dir_roots`], and likewise
+	/// replaces it.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // /usr/share will be ignored.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .require_file_roots()
+	///     .with_paths(["/usr/share", "/etc/hosts"])
+	///     .collect();
+	/// ```
+	pub const fn require_file_roots(mut self) -> Self {
+		self.root_dirs = Some(false);
+		self
+	}
+
+	#[must_use]
+	/// # With Root Filter.
+	///
+	/// Only queue up roots — paths passed to [`Dowser::with_path`] and kin —
+	/// for which the callback returns `true`. The callback receives the
+	/// canonical path.
+	///
+	/// This is entirely separate from any filtering applied to the crawl
+	/// results, allowing you to validate your _inputs_ differently than
+	/// your _outputs_. Roots that fail the test are simply ignored.
+	///
+	/// Calling this more than once replaces the previous filter.
+	///
+	/// Note: like the `without_*` methods, this should be called _before_ any
+	/// `with_*` methods, otherwise it won't have anything to filter.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::{Path, PathBuf};
+	///
+	/// // Only crawl directory roots.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_root_filter(Path::is_dir)
+	///     .with_paths(["/usr/share", "/etc/hosts"])
+	///     .collect();
+	/// ```
+	pub fn with_root_filter<F>(mut self, cb: F) -> Self
+	where F: Fn(&Path) -> bool + Send + Sync + 'static {
+		self.root_filter.replace(Callback::new(cb));
+		self
+	}
+
+	#[must_use]
+	/// # Defer Roots.
+	///
+	/// By default, roots — paths passed to [`Dowser::with_path`] and kin —
+	/// are resolved immediately, and file roots are run through the
+	/// [filter](Dowser::and_filter) and queued for yielding right then and
+	/// there.
+	///
+	/// Calling this method changes that for subsequently-added roots, holding
+	/// them as-are until the crawl actually starts, so that every path —
+	/// root or discovered — is resolved and tested at the same stage. As a
+	/// side effect, settings like [`Dowser::with_root_filter`] can then be
+	/// applied in any order.
+	///
+	/// Relative paths are still joined to the
+	/// [base directory](Dowser::with_base_dir), if any, right away.
+	/// [`Dowser::try_with_path`], which has to check the path to report on
+	/// it, is unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Nothing is resolved until collection.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .defer_roots()
+	///     .with_paths(["/usr/share", "/etc/hosts"])
+	///     .collect();
+	/// ```
+	pub const fn defer_roots(mut self) -> Self {
+		self.defer_roots = true;
+		self
+	}
+
+	#[must_use]
+	/// # With Directory Filter.
+	///
+	/// Only _report_ directories for which the callback returns `true`, as
+	/// applicable to directory-yielding methods like [`Dowser::split`] and
+	/// [`Dowser::only_empty_dirs`]. The callback receives the canonical
+	/// path.
+	///
+	/// This has no effect on which directories are _crawled_ — use
+	/// [`Dowser::without_dir_names`] and kin for that — so a directory can
+	/// be descended into without being reported, or vice versa.
+	///
+	/// Calling this more than once replaces the previous filter.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// // Report only the directories with a README.
+	/// let (dirs, files) = Dowser::default()
+	///     .with_dir_filter(|p| p.join("README.md").is_file())
+	///     .with_path("/my/projects")
+	///     .split();
+	/// ```
+	pub fn with_dir_filter<F>(mut self, cb: F) -> Self
+	where F: Fn(&Path) -> bool + Send + Sync + 'static {
+		self.dir_filter.replace(Callback::new(cb));
+		self
+	}
+
+	#[must_use]
+	/// # And Filter.
+	///
+	/// Only yield files for which the callback returns `true`. The callback
+	/// receives the canonical path.
+	///
+	/// Unlike [`Dowser::with_root_filter`], filters added this way compose:
+	/// calling this more than once ANDs the callbacks together, evaluating
+	/// them in the order they were added, and stopping at the first one to
+	/// return `false`. Cheap tests should therefore come first.
+	///
+	/// This only affects which _files_ are yielded; directories are crawled
+	/// regardless. Call order doesn't matter: file roots added earlier are
+	/// checked on the spot. It works with any of the consuming methods,
+	/// including [`Dowser::into_vec_filtered`], whose callback is simply
+	/// one more test applied at the end.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, Extension};
+	/// use std::path::PathBuf;
+	///
+	/// const EXT: Extension = Extension::new2(*b"gz");
+	///
+	/// // Gzipped files that aren't empty.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .and_filter(|p| EXT == p)
+	///     .and_filter(|p| p.metadata().is_ok_and(|m| 0 < m.len()))
+	///     .with_path("/usr/share/man")
+	///     .collect();
+	/// ```
+	pub fn and_filter<F>(mut self, cb: F) -> Self
+	where F: Fn(&Path) -> bool + Send + Sync + 'static {
+		// Files already buffered — roots, mostly — were queued before this
+		// filter existed.
+		self.files.retain(|p| cb(p));
+
+		self.filter = Some(match self.filter.take() {
+			Some(old) => old.and(cb),
+			None => Callback::new(cb),
+		});
+		self
+	}
+
+	#[must_use]
+	/// # With Name Filter.
+	///
+	/// Only yield files whose _name_ — final path component — passes the
+	/// callback.
+	///
+	/// The name is passed as a raw [`OsStr`], so non-UTF-8 names are
+	/// handled correctly, and there's no need to pick the name back out of
+	/// the full path yourself.
+	///
+	/// This is a convenience wrapper around [`Dowser::and_filter`], and
+	/// composes with it (and itself) the same way: all filters must pass,
+	/// in the order they were added.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Find suspiciously long file names.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_name_filter(|name| 200 < name.len())
+	///     .with_path("/srv/uploads")
+	///     .collect();
+	/// ```
+	pub fn with_name_filter<F>(self, cb: F) -> Self
+	where F: Fn(&OsStr) -> bool + Send + Sync + 'static {
+		self.and_filter(move |p| p.file_name().is_some_and(&cb))
+	}
+
+	#[must_use]
+	/// # With Extension (String).
+	///
+	/// Only yield files with the given extension, parsed from a
+	/// user-supplied string — e.g. a CLI argument like `jpg` or `.JPG` —
+	/// via [`Extension::parse_lenient`].
+	///
+	/// This is a convenience wrapper around [`Dowser::and_filter`], and
+	/// composes with it the same way. To allow any of several extensions,
+	/// use [`Dowser::with_extensions_str`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_extension_str("gz")
+	///     .with_path("/usr/share/man")
+	///     .collect();
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if the extension is invalid. If that's a
+	/// possibility, check it first with [`Extension::parse_lenient`].
+	pub fn with_extension_str(self, ext: &str) -> Self {
+		let ext = Extension::parse_lenient(ext).expect("Invalid extension.");
+		self.and_filter(move |p| ext == p)
+	}
+
+	#[must_use]
+	/// # With Extensions (String).
+	///
+	/// Only yield files with _any_ of the given extensions, parsed from
+	/// user-supplied strings via [`Extension::parse_lenient`].
+	///
+	/// This is a convenience wrapper around [`Dowser::and_filter`], and
+	/// composes with it the same way.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // e.g. --ext jpg,png,gif
+	/// let arg = "jpg,png,gif";
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_extensions_str(arg.split(','))
+	///     .with_path("/srv/images")
+	///     .collect();
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if any of the extensions are invalid. If that's a
+	/// possibility, check them first with [`Extension::parse_lenient`].
+	pub fn with_extensions_str<S, I>(self, exts: I) -> Self
+	where S: AsRef<str>, I: IntoIterator<Item=S> {
+		let exts: Vec<Extension> = exts.into_iter()
+			.map(|e| Extension::parse_lenient(e.as_ref()).expect("Invalid extension."))
+			.collect();
+		self.and_filter(move |p| Extension::first_match(p, &exts).is_some())
+	}
+}
+
+impl Dowser {
+	#[must_use]
+	/// # With Symlink Report.
+	///
+	/// Keep track of every symlink followed during the crawl, and where it
+	/// led. The results can be retrieved afterward with
+	/// [`Dowser::symlink_report`].
+	///
+	/// This can be helpful for figuring out why a crawl turned up files from
+	/// unexpected places. (Only symlinks found _inside_ directories are
+	/// tracked; roots passed to [`Dowser::with_path`] and kin are not.)
+	///
+	/// If symlink-following has been disabled, the links will instead be
+	/// reported as they are skipped, though their targets are still
+	/// resolved for the report. See also [`Dowser::symlinks_only`].
+	///
+	/// Broken links are never reported here; see
+	/// [`Dowser::with_broken_symlink_report`] for those.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let mut crawl = Dowser::default()
+	///     .with_symlink_report()
+	///     .with_path("/usr/share/man");
+	///
+	/// let files: Vec<PathBuf> = crawl.by_ref().collect();
+	/// for (link, target) in crawl.symlink_report() {
+	///     println!("{} -> {}", link.display(), target.display());
+	/// }
+	/// ```
+	pub fn with_symlink_report(mut self) -> Self {
+		self.symlink_report.get_or_insert_with(Vec::new);
+		self
+	}
+
+	#[must_use]
+	/// # Symlink Report.
+	///
+	/// Return the `(link, target)` pairs for every symlink followed (or
+	/// skipped) so far, in the order encountered. Link paths are as-found;
+	/// targets are canonical.
+	///
+	/// This will always be empty unless [`Dowser::with_symlink_report`] was
+	/// enabled.
+	pub fn symlink_report(&self) -> &[(PathBuf, PathBuf)] {
+		self.symlink_report.as_deref().unwrap_or_default()
+	}
+
+	#[must_use]
+	/// # With Broken Symlink Report.
+	///
+	/// Keep track of every symlink encountered during the crawl whose
+	/// target could not be resolved, along with the reason. The results can
+	/// be retrieved afterward with [`Dowser::broken_symlinks`].
+	///
+	/// Ordinarily such links are silently skipped, which is fine for file
+	/// discovery, but not so great for link auditing. (As with
+	/// [`Dowser::with_symlink_report`], only symlinks found _inside_
+	/// directories are tracked.)
+	///
+	/// Links are checked whether or not symlink-following is enabled.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let mut crawl = Dowser::default()
+	///     .without_symlinks()
+	///     .with_broken_symlink_report()
+	///     .with_path("/etc");
+	///
+	/// crawl.by_ref().for_each(drop);
+	/// for (link, err) in crawl.broken_symlinks() {
+	///     eprintln!("Broken: {} ({err})", link.display());
+	/// }
+	/// ```
+	pub fn with_broken_symlink_report(mut self) -> Self {
+		self.broken_symlinks.get_or_insert_with(Vec::new);
+		self
+	}
+
+	#[must_use]
+	/// # Broken Symlinks.
+	///
+	/// Return the `(link, error)` pairs for every unresolvable symlink found
+	/// so far, in the order encountered. Link paths are as-found.
+	///
+	/// (The errors are wrapped in an [`Arc`] to keep [`Dowser`] cloneable.)
+	///
+	/// This will always be empty unless
+	/// [`Dowser::with_broken_symlink_report`] was enabled.
+	pub fn broken_symlinks(&self) -> &[(PathBuf, Arc<std::io::Error>)] {
+		self.broken_symlinks.as_deref().unwrap_or_default()
+	}
+}
+
+impl Dowser {
+	#[must_use]
+	/// # Permission Denied Count.
+	///
+	/// Return the number of directories that could not be read due to
+	/// insufficient permissions so far.
+	///
+	/// This will always be zero unless [`Dowser::on_permission_denied`] was
+	/// set to [`PermissionPolicy::Count`] or [`PermissionPolicy::Abort`].
+	pub const fn permission_denied(&self) -> usize { self.permission_denied }
+
+	#[must_use]
+	/// # Too Deep.
+	///
+	/// Return the directories skipped so far for exceeding the
+	/// [depth ceiling](Dowser::with_depth_ceiling), in the order
+	/// encountered.
+	///
+	/// In practice, this should almost always be empty.
+	pub fn too_deep(&self) -> &[PathBuf] { &self.too_deep }
+
+	#[must_use]
+	/// # Truncated Directories.
+	///
+	/// Return the directories read only partially so far because they held
+	/// more entries than the [limit](Dowser::with_max_entries_per_dir), in
+	/// the order encountered.
+	pub fn truncated_dirs(&self) -> &[PathBuf] { &self.truncated }
+
+	#[must_use]
+	/// # Changed Types.
+	///
+	/// Return the directories that were no longer directories by the time
+	/// the crawl got around to reading them, in the order encountered.
+	///
+	/// This can happen when the tree is being modified mid-crawl. Such paths
+	/// are re-resolved and, if they've become files, yielded like any other
+	/// (filters and dedup permitting). This is best-effort only; the tree
+	/// can always change again.
+	pub fn type_changed(&self) -> &[PathBuf] { &self.type_changed }
+
+	#[must_use]
+	/// # Failed Reads.
+	///
+	/// Return the directories — and errors — that could not be read so far
+	/// despite [retrying](Dowser::with_retry), in the order encountered.
+	///
+	/// This is always empty if retrying is disabled.
+	pub fn failed_reads(&self) -> &[(PathBuf, Arc<std::io::Error>)] {
+		&self.failed_reads
+	}
+
+	#[must_use]
+	/// # Buffered Files.
+	///
+	/// Return the files that have been found — or queued directly as roots
+	/// — but not yet yielded, without disturbing the crawl.
+	///
+	/// Before iteration begins, this will hold exactly the file roots passed
+	/// to [`Dowser::with_path`] and kin, which are resolved eagerly, making
+	/// it handy for validating those separately from the (lazy) directory
+	/// crawl. Note that files are yielded from the _end_ of the buffer.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let crawl = Dowser::default().with_paths(["/etc/hosts", "/usr/share"]);
+	/// for file in crawl.buffered_files() {
+	///     println!("Explicit file: {}", file.display());
+	/// }
+	/// ```
+	pub fn buffered_files(&self) -> &[PathBuf] { self.files.as_slice() }
+
+	#[must_use]
+	/// # Estimate.
+	///
+	/// Return a (very) rough guess as to how many files the crawl will turn
+	/// up, suitable for sizing a progress bar.
+	///
+	/// This works by reading — but not descending into — a small sample of
+	/// the pending directories and [deferred](Dowser::defer_roots) roots,
+	/// assuming each subdirectory found will hold about as many entries as
+	/// the average directory probed, and scaling the result up to cover the
+	/// rest of the queue.
+	///
+	/// It is cheap, but only approximate; depending on the shape of the tree
+	/// it may be wildly off, particularly for deep hierarchies. Treat it
+	/// accordingly! [Lazy](Dowser::with_lazy_paths) roots not yet pulled
+	/// aren't counted at all.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let crawl = Dowser::from("/usr/share");
+	/// let total = crawl.estimate();
+	/// for (i, file) in crawl.enumerate() {
+	///     eprint!("\r{i}/~{total}");
+	/// }
+	/// ```
+	pub fn estimate(&self) -> usize {
+		/// # Maximum Sample Size.
+		const SAMPLE: usize = 32;
+
+		let total = self.dirs.len() + self.deferred.len();
+		if total == 0 { return self.files.len(); }
+
+		// Spread the sample evenly across the queue.
+		let step = total.div_ceil(SAMPLE);
+		let mut sampled = 0_usize;
+		let mut probed = 0_usize;
+		let mut files = 0_usize;
+		let mut dirs = 0_usize;
+		let queue = self.dirs.iter().map(|(p, _)| p.as_path())
+			.chain(self.deferred.iter().map(PathBuf::as_path));
+		for p in queue.step_by(step) {
+			sampled += 1;
+			match std::fs::read_dir(p) {
+				Ok(rd) => {
+					probed += 1;
+					for e in rd.flatten() {
+						if e.file_type().is_ok_and(|ft| ft.is_dir()) { dirs += 1; }
+						else { files += 1; }
+					}
+				},
+				// Deferred roots might be files.
+				Err(_) => if p.is_file() { files += 1; },
+			}
+		}
+
+		let avg = (files + dirs).div_ceil(probed.max(1));
+		let found = files.saturating_add(dirs.saturating_mul(avg));
+		self.files.len().saturating_add(found.saturating_mul(total) / sampled)
+	}
+
+	#[must_use]
+	/// # Pending Directories.
+	///
+	/// Return the directories queued up for crawling but not yet read, in
+	/// the order they'll be read (per the [traversal order](Dowser::with_order)).
+	///
+	/// This can be useful for debugging a crawl that has stalled: whatever
+	/// is being read at the moment will have been popped off the queue, but
+	/// the remaining entries narrow things down considerably.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let mut crawl = Dowser::default().with_path("/mnt");
+	/// for file in crawl.by_ref().take(100) {
+	///     println!("{}", file.display());
+	/// }
+	///
+	/// for dir in crawl.pending_dirs() {
+	///     eprintln!("Still to do: {}", dir.display());
+	/// }
+	/// ```
+	pub fn pending_dirs(&self) -> impl ExactSizeIterator<Item=&Path> {
+		self.dirs.iter().rev().map(|(p, _)| p.as_path())
+	}
+}
+
+#[cfg(feature = "archives")]
+impl Dowser {
+	#[must_use]
+	#[inline]
+	/// # With Archives.
+	///
+	/// Crawl _into_ the given kinds of archives as if they were directories,
+	/// yielding virtual paths for each of their (regular file) members —
+	/// like `/data/site.zip!/index.html` — in place of the archives
+	/// themselves.
+	///
+	/// Archive members cannot be canonicalized, but are deduplicated all the
+	/// same using the combined archive/member path.
+	///
+	/// Archives that cannot be parsed are yielded as regular files. Nested
+	/// archives are not expanded.
+	///
+	/// Note: this should be called _before_ any `with_*` methods, otherwise
+	/// archive roots will have already been queued up as regular files.
+	///
+	/// This method requires the `archives` crate feature.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{
+	///     ArchiveKinds,
+	///     Dowser,
+	/// };
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_archives(ArchiveKinds::ALL)
+	///     .with_path("/data")
+	///     .collect();
+	/// ```
+	pub const fn with_archives(mut self, kinds: crate::ArchiveKinds) -> Self {
+		self.archives = kinds;
+		self
+	}
+}
+
+#[cfg(feature = "glob")]
+impl Dowser {
+	/// # With Glob Roots.
+	///
+	/// Expand a shell-style glob pattern — like `/data/2024-*` — against the
+	/// filesystem, and queue up each matching path as a root, exactly as if
+	/// each had been passed to [`Dowser::with_path`].
+	///
+	/// This is meant for patterns that arrive unexpanded, from a config file
+	/// or the like. The pattern only _selects the roots_; it has no bearing on
+	/// what is found beneath them. To filter the crawl results instead, see
+	/// [`Dowser::and_filter`] or [`Dowser::with_ignore_files`].
+	///
+	/// Relative patterns are resolved against the
+	/// [base directory](Dowser::with_base_dir), if any, or the current working
+	/// directory. Matches that can't be read are silently skipped.
+	///
+	/// This method requires the `glob` crate feature.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_glob_roots("/data/2024-*/")
+	///     .unwrap()
+	///     .collect();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors encountered while parsing the pattern.
+	pub fn with_glob_roots(mut self, pattern: &str)
+	-> Result<Self, glob::PatternError> {
+		let pattern = match self.base.as_deref() {
+			Some(base) if Path::new(pattern).is_relative() => Cow::Owned(format!(
+				"{}/{pattern}",
+				glob::Pattern::escape(&base.to_string_lossy()),
+			)),
+			_ => Cow::Borrowed(pattern),
+		};
+
+		for path in glob::glob(&pattern)?.flatten() { self.queue_path(&path); }
+		Ok(self)
+	}
+}
+
+#[cfg(feature = "rayon")]
+impl Dowser {
+	#[inline]
+	#[must_use]
+	/// # Into Parallel Iterator.
+	///
+	/// Crawl the paths in parallel, returning a [`rayon`] parallel iterator
+	/// that can be composed with rayon adaptors directly rather than
+	/// collected via [`Dowser::into_vec`] first.
+	///
+	/// All of the builder's configuration — filters, exclusions, depth
+	/// limits, etc. — remains in effect. Directory reads are split across
+	/// the workers, along with the files they turn up.
+	///
+	/// This is an alias of [`IntoParallelIterator::into_par_iter`](rayon::iter::IntoParallelIterator::into_par_iter);
+	/// see [`DowserPar`](crate::DowserPar) for more details.
+	///
+	/// This method requires the `rayon` crate feature.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use rayon::iter::ParallelIterator;
+	///
+	/// let total: u64 = Dowser::default()
+	///     .and_filter(|p| p.extension().is_some_and(|e| e == "gz"))
+	///     .with_path("/usr/share")
+	///     .par_iter()
+	///     .filter_map(|p| std::fs::metadata(p).ok())
+	///     .map(|m| m.len())
+	///     .sum();
+	/// ```
+	pub fn par_iter(self) -> crate::DowserPar {
+		rayon::iter::IntoParallelIterator::into_par_iter(self)
+	}
+
+	#[must_use]
+	/// # With Thread Pool.
+	///
+	/// Confine [`Dowser::par_iter`] to a specific rayon thread pool, such
+	/// as a dedicated, size-limited one, so a big crawl can't starve other
+	/// work sharing the global pool.
+	///
+	/// Note: the regular (sequential) iterator and collection methods are
+	/// unaffected; they always run on the calling thread.
+	///
+	/// This method requires the `rayon` crate feature.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use rayon::iter::ParallelIterator;
+	/// use std::sync::Arc;
+	///
+	/// let pool = rayon::ThreadPoolBuilder::new()
+	///     .num_threads(2)
+	///     .build()
+	///     .unwrap();
+	///
+	/// let count = Dowser::default()
+	///     .with_thread_pool(Arc::new(pool))
+	///     .with_path("/usr/share")
+	///     .par_iter()
+	///     .count();
+	/// ```
+	pub fn with_thread_pool(mut self, pool: Arc<rayon::ThreadPool>) -> Self {
+		self.pool.replace(pool);
+		self
+	}
+
+	#[must_use]
+	/// # Without Paths (Parallel).
+	///
+	/// This works just like [`Dowser::without_paths`], except the paths are
+	/// canonicalized in parallel, which can make a big difference for long
+	/// exclusion lists, particularly on slower or networked filesystems.
+	///
+	/// The work runs in the [thread pool](Dowser::with_thread_pool), if
+	/// specified, or else whatever pool the caller is in (usually the global
+	/// one).
+	///
+	/// This method requires the `rayon` crate feature.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let blocklist: Vec<PathBuf> = Vec::new(); // Imagine lots of paths…
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .par_without_paths(blocklist)
+	///     .with_path("/my/dir")
+	///     .collect();
+	/// ```
+	pub fn par_without_paths<P, I>(mut self, paths: I) -> Self
+	where P: AsRef<Path> + Send, I: rayon::iter::IntoParallelIterator<Item=P> + Send {
+		use rayon::iter::ParallelIterator;
+
+		let base = self.base.as_deref();
+		let resolve = move || paths.into_par_iter()
+			.filter_map(|p|
+				std::fs::canonicalize(rebase(base, p.as_ref())).ok()
+					.map(|p| Entry::hash_path(&p, p.is_dir()))
+			)
+			.collect::<Vec<u64>>();
+
+		let hashes = match &self.pool {
+			Some(pool) => pool.install(resolve),
+			None => resolve(),
+		};
+		self.seen.extend(hashes);
+		self
+	}
+
+	/// # Prepare for Parallel Crawl.
+	///
+	/// Resolve any lazy roots, start the clock, if budgeted, and make sure
+	/// there's a shared seen set for the workers to deduplicate against.
+	///
+	/// Everything seen so far is moved into a read-only baseline so that
+	/// workers don't have to copy it.
+	pub(super) fn par_prepare(&mut self) {
+		self.resolve_lazy();
+		if self.time_budget.is_some() && self.started.is_none() {
+			self.started = Some(Instant::now());
+		}
+		if self.shared.is_none() { self.shared.replace(SharedSeen::default()); }
+		self.baseline = Some(Arc::new(std::mem::take(&mut self.seen)));
+	}
+
+	/// # Parallel Split.
+	///
+	/// Hand off roughly half the pending directories and files to a new
+	/// instance, reading the next directory first if there aren't enough
+	/// queued to go around.
+	///
+	/// Returns `None` if there isn't anything worth splitting.
+	pub(super) fn par_split(&mut self) -> Option<Self> {
+		if self.dirs.len() < 2 {
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+		}
+
+		let dirs =
+			if 2 <= self.dirs.len() { self.dirs.split_off(self.dirs.len() / 2) }
+			else { VecDeque::new() };
+		let files =
+			if 2 <= self.files.len() { self.files.split_off(self.files.len() / 2) }
+			else { Vec::new() };
+		if dirs.is_empty() && files.is_empty() { return None; }
+
+		Some(self.par_worker(dirs, files))
+	}
+
+	/// # Parallel Worker.
+	///
+	/// Return a new instance with the same settings as `self`, but fresh
+	/// state: the given queues, an empty local seen set — the shared set
+	/// and baseline handle deduplication — and empty reports.
+	///
+	/// The settings are mostly small or reference-counted, so are cheap to
+	/// copy. The exception is the ignore patterns, which the worker needs
+	/// to honor in the directories it's been handed.
+	fn par_worker(&self, dirs: VecDeque<(PathBuf, usize)>, files: Vec<PathBuf>) -> Self {
+		Self {
+			files,
+			file_depths: None,
+			dirs,
+			order: self.order,
+			lazy: None,
+			defer_roots: self.defer_roots,
+			deferred: Vec::new(),
+			seen: HashSet::default(),
+			shared: self.shared.clone(),
+			min_depth: self.min_depth,
+			max_depth: self.max_depth,
+			depth_ceiling: self.depth_ceiling,
+			too_deep: Vec::new(),
+			symlinks: self.symlinks,
+			symlink_hops: self.symlink_hops,
+			dir_limit: self.dir_limit,
+			entry_limit: self.entry_limit,
+			truncated: Vec::new(),
+			type_changed: Vec::new(),
+			source: self.source.clone(),
+			prefetch: self.prefetch,
+			nlink_hint: self.nlink_hint,
+			retry: self.retry,
+			failed_reads: Vec::new(),
+			time_budget: self.time_budget,
+			started: self.started,
+			root_dirs: self.root_dirs,
+			root_filter: self.root_filter.clone(),
+			confine: self.confine.clone(),
+			permission_policy: self.permission_policy,
+			permission_denied: 0,
+			dir_filter: self.dir_filter.clone(),
+			dir_names: self.dir_names.clone(),
+			ignores: self.ignores.clone(),
+			link_excludes: self.link_excludes.clone(),
+			filter: self.filter.clone(),
+			base: self.base.clone(),
+			list_exts: self.list_exts.clone(),
+			bundle_exts: self.bundle_exts.clone(),
+			symlink_report: self.symlink_report.as_ref().map(|_| Vec::new()),
+			broken_symlinks: self.broken_symlinks.as_ref().map(|_| Vec::new()),
+			#[cfg(feature = "archives")]
+			archives: self.archives,
+			pool: None,
+			baseline: self.baseline.clone(),
+			#[cfg(feature = "regex")]
+			exclude: self.exclude.clone(),
+		}
+	}
+}
+
+#[cfg(feature = "rayon")]
+impl rayon::iter::IntoParallelIterator for Dowser {
+	type Iter = crate::DowserPar;
+	type Item = PathBuf;
+
+	/// # Into Parallel Iterator.
+	///
+	/// Crawl the paths in parallel. See [`DowserPar`](crate::DowserPar) for
+	/// more details.
+	fn into_par_iter(mut self) -> Self::Iter {
+		let pool = self.pool.take();
+		crate::DowserPar::new(self, pool)
+	}
+}
+
+#[cfg(feature = "regex")]
+impl Dowser {
+	/// # Exclude by Regex.
+	///
+	/// Prevent any paths matching one or more of the given regular
+	/// expressions from being crawled or included in the output. Matching
+	/// directories are pruned entirely; matching files are simply dropped.
+	///
+	/// The patterns are compiled into a single
+	/// [`RegexSet`](regex::bytes::RegexSet) and tested against the full,
+	/// canonical path bytes of every entry, roots included. Directory paths
+	/// do not have trailing slashes, so to exclude everything called
+	/// `target`, for example, use `/target$` rather than `/target/`.
+	///
+	/// Calling this more than once replaces the previous patterns.
+	///
+	/// Note: like the `without_*` methods, this should be called _before_ any
+	/// `with_*` methods, just in case the sets overlap.
+	///
+	/// This method requires the `regex` crate feature.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .regex_exclude(&[r"/(\.git|target)$", r"(?i)\.tmp$"])
+	///     .unwrap()
+	///     .with_path("/my/project")
+	///     .collect();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors encountered while compiling the
+	/// patterns.
+	pub fn regex_exclude(mut self, patterns: &[&str])
+	-> Result<Self, regex::Error> {
+		self.exclude =
+			if patterns.is_empty() { None }
+			else { Some(regex::bytes::RegexSet::new(patterns)?) };
+		Ok(self)
+	}
+}
+
+impl Dowser {
+	/// # Load Paths From File.
+	///
+	/// Queue up multiple file and/or directory paths from a text file, one
+	/// entry per line.
+	///
+	/// Lines are trimmed and ignored if empty, but otherwise resolved the
+	/// same as if passed directly to methods like [`Dowser::with_path`], i.e.
+	/// relative to the current working directory — or
+	/// [base directory](Dowser::with_base_dir), if any — rather than the text
+	/// file itself.
+	///
+	/// For that reason, it is recommended that all paths stored in text files
+	/// be absolute to avoid any ambiguity, or that the file be loaded with
+	/// [`Dowser::read_paths_from_file_relative`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Read the paths from list.txt.
+	/// let mut crawler = Dowser::default();
+	/// crawler.read_paths_from_file("list.txt").unwrap();
+	///
+	/// // Crunch into a vec.
+	/// let files: Vec::<PathBuf> = crawler.collect();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This method will bubble up any errors encountered while trying to read
+	/// the text file.
+	pub fn read_paths_from_file<P: AsRef<Path>>(&mut self, src: P)
+	-> Result<(), std::io::Error> {
+		let raw = std::fs::read_to_string(src)?;
+		for line in raw.lines() {
+			let line = line.trim();
+			if ! line.is_empty() { self.queue_path(Path::new(line)); }
+		}
+
+		Ok(())
+	}
+
+	/// # Load Paths From File (Relative).
+	///
+	/// This works just like [`Dowser::read_paths_from_file`], except relative
+	/// entries are resolved against the directory containing the text file
+	/// itself, rather than the current working directory or
+	/// [base directory](Dowser::with_base_dir). Absolute entries are used
+	/// as-are.
+	///
+	/// This is usually what you want for manifests that travel alongside the
+	/// files they reference.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // A line reading "uploads" will crawl /srv/tenant/uploads.
+	/// let mut crawler = Dowser::default();
+	/// crawler.read_paths_from_file_relative("/srv/tenant/list.txt").unwrap();
+	///
+	/// let files: Vec::<PathBuf> = crawler.collect();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This method will bubble up any errors encountered while trying to
+	/// resolve or read the text file.
+	pub fn read_paths_from_file_relative<P: AsRef<Path>>(&mut self, src: P)
+	-> Result<(), std::io::Error> {
+		let src = std::fs::canonicalize(rebase(self.base.as_deref(), src.as_ref()))?;
+		let raw = std::fs::read_to_string(&src)?;
+		let dir = src.parent();
+		for line in raw.lines() {
+			let line = line.trim();
+			if ! line.is_empty() {
+				let path = rebase(dir, Path::new(line));
+				if self.defer_roots { self.deferred.push(path.into_owned()); }
+				else if let Some(e) = Entry::from_path(path) { self.push_root(e); }
+			}
+		}
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// # With List Files.
+	///
+	/// Treat any file with the given extension encountered during the crawl
+	/// — root or otherwise — as a list of paths to crawl, rather than a
+	/// result.
+	///
+	/// Such files are read and queued exactly like
+	/// [`Dowser::read_paths_from_file`], so the same caveats about relative
+	/// paths apply. Lists may reference other lists; each is only expanded
+	/// once, so cycles are harmless.
+	///
+	/// Files that cannot be read as text are yielded as regular files.
+	///
+	/// This can be called more than once to register additional extensions.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, Extension};
+	/// use std::path::PathBuf;
+	///
+	/// // Crawl a directory of manifests, each pointing elsewhere.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_list_files(Extension::new4(*b"list"))
+	///     .with_path("/srv/manifests")
+	///     .collect();
+	/// ```
+	pub fn with_list_files(mut self, ext: Extension) -> Self {
+		if ! self.list_exts.contains(&ext) { self.list_exts.push(ext); }
+		self
+	}
+
+	#[must_use]
+	/// # With Bundle Directories.
+	///
+	/// Treat any directory with the given extension encountered during the
+	/// crawl — like a macOS `.app` — as an opaque unit, yielding its path
+	/// as if it were a file rather than descending into it.
+	///
+	/// Bundles are otherwise subject to the same rules as files, including
+	/// any [filters](Dowser::and_filter) and [`Dowser::with_min_depth`].
+	///
+	/// Roots are exempt, as they were explicitly requested; a bundle passed
+	/// to [`Dowser::with_path`] will be crawled like any other directory.
+	///
+	/// This can be called more than once to register additional extensions.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, Extension};
+	/// use std::path::PathBuf;
+	///
+	/// // Applications are returned whole.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_bundle_dirs(Extension::new3(*b"app"))
+	///     .with_path("/Applications")
+	///     .collect();
+	/// ```
+	pub fn with_bundle_dirs(mut self, ext: Extension) -> Self {
+		if ! self.bundle_exts.contains(&ext) { self.bundle_exts.push(ext); }
+		self
+	}
+}
+
+impl Dowser {
+	/// # Write Index.
+	///
+	/// Write a list of paths — typically crawl results — to `w` in a
+	/// compact binary format, for later retrieval via [`Dowser::read_index`].
+	///
+	/// Each path is stored as a little-endian `u32` byte length followed by
+	/// the raw bytes, so unlike a plain text list, there's no trouble with
+	/// paths that contain newlines.
+	///
+	/// Note: the writer is used as-is; wrap it in a
+	/// [`BufWriter`](std::io::BufWriter) if it isn't already buffered.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::{
+	///     fs::File,
+	///     io::BufWriter,
+	/// };
+	///
+	/// let files = Dowser::from("/usr/share").into_vec();
+	/// let out = File::create("/tmp/share.idx").unwrap();
+	/// Dowser::write_index(&files, BufWriter::new(out)).unwrap();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any I/O errors, or return an error of kind
+	/// [`InvalidInput`](ErrorKind::InvalidInput) if a path is too long to be
+	/// indexed. (On non-Unix platforms, paths that aren't valid UTF-8 are
+	/// likewise rejected.)
+	pub fn write_index<W: Write>(paths: &[PathBuf], mut w: W) -> Result<(), std::io::Error> {
+		for p in paths {
+			let raw = index_bytes(p)?;
+			let len = u32::try_from(raw.len()).map_err(|_| ErrorKind::InvalidInput)?;
+			w.write_all(&len.to_le_bytes())?;
+			w.write_all(raw)?;
+		}
+
+		w.flush()
+	}
+
+	/// # Read Index.
+	///
+	/// Read back a list of paths previously saved with
+	/// [`Dowser::write_index`].
+	///
+	/// The paths are returned exactly as written; if you need to verify
+	/// they still exist, pass them back through [`Dowser::with_paths`] or
+	/// similar.
+	///
+	/// Note: the reader is used as-is; wrap it in a
+	/// [`BufReader`](std::io::BufReader) if it isn't already buffered.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::{
+	///     fs::File,
+	///     io::BufReader,
+	/// };
+	///
+	/// let src = File::open("/tmp/share.idx").unwrap();
+	/// let files = Dowser::read_index(BufReader::new(src)).unwrap();
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any I/O errors, including
+	/// [`UnexpectedEof`](ErrorKind::UnexpectedEof) if the data is truncated.
+	pub fn read_index<R: Read>(mut r: R) -> Result<Vec<PathBuf>, std::io::Error> {
+		let mut out = Vec::new();
+		let mut len = [0_u8; 4];
+		loop {
+			// Read the length, stopping if we're cleanly out of data, i.e.
+			// there isn't even a first byte. (Running out partway through
+			// is still an error.)
+			match r.read_exact(&mut len[..1]) {
+				Ok(()) => { r.read_exact(&mut len[1..])?; },
+				Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+				Err(e) => return Err(e),
+			}
+
+			let len = usize::try_from(u32::from_le_bytes(len))
+				.map_err(|_| ErrorKind::InvalidData)?;
+			let mut raw = Vec::new();
+			r.by_ref().take(len as u64).read_to_end(&mut raw)?;
+			if raw.len() != len { return Err(ErrorKind::UnexpectedEof.into()); }
+
+			out.push(index_path(raw)?);
+		}
+
+		Ok(out)
+	}
+}
+
+impl Dowser {
+	#[must_use]
+	#[inline]
+	/// # Without Path.
+	///
+	/// This will prevent the provided directory or file from being crawled or
+	/// included in the output.
+	///
+	/// Note: without-path(s) should be specified before with-path(s), just in
+	/// case the sets overlap.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .without_path("/my/dir/ignore")
+	///     .with_path("/my/dir")
+	///     .collect();
+	/// ```
+	pub fn without_path<P>(mut self, path: P) -> Self
+	where P: AsRef<Path> {
+		if let Ok(p) = std::fs::canonicalize(rebase(self.base.as_deref(), path.as_ref())) {
+			let hash = Entry::hash_path(&p, p.is_dir());
+			self.seen.insert(hash);
+		}
+
+		self
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Without Paths.
+	///
+	/// This will prevent the provided directories or files from being crawled
+	/// or included in the output.
+	///
+	/// Note: without-path(s) should be specified before with-path(s), just in
+	/// case the sets overlap.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .without_paths(&["/my/dir/ignore"])
+	///     .with_path("/my/dir")
+	///     .collect();
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if you try to pass a single `Path` or `PathBuf` object
+	/// directly to this method (instead of a collection of same). Use
+	/// [`Dowser::without_path`] to add such an object directly.
+	pub fn without_paths<P, I>(mut self, paths: I) -> Self
+	where P: AsRef<Path>, I: IntoIterator<Item=P> {
+		assert!(! is_singular_path(&paths), "Dowser::without_paths requires an Iterator of paths, not a direct Path/PathBuf object.");
+
+		let base = self.base.as_deref();
+		self.seen.extend(paths.into_iter().filter_map(|p|
+			std::fs::canonicalize(rebase(base, p.as_ref())).ok()
+				.map(|p| Entry::hash_path(&p, p.is_dir()))
+		));
+		self
+	}
+
+	#[must_use]
+	/// # Without Symlink Path.
+	///
+	/// Prevent the crawl from following one specific symlink, without
+	/// excluding its target.
+	///
+	/// Unlike [`Dowser::without_path`], which resolves the path first and
+	/// so excludes whatever it _points to_, this matches the link itself.
+	/// If the target is reachable some other way, it will still be found.
+	///
+	/// If the path is not a symlink, nothing happens.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Don't take the shortcut, but do find the files the long way.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .without_symlink_path("/my/project/shortcut")
+	///     .with_path("/my/project")
+	///     .collect();
+	/// ```
+	pub fn without_symlink_path<P>(mut self, path: P) -> Self
+	where P: AsRef<Path> {
+		let path = rebase(self.base.as_deref(), path.as_ref());
+		if std::fs::symlink_metadata(&path).is_ok_and(|m| m.file_type().is_symlink()) {
+			if let Some(link) = canonical_link(&path) {
+				self.link_excludes.insert(Entry::hash_path(&link, false));
+			}
+		}
+
+		self
+	}
+
+	#[must_use]
+	/// # With Shared Seen Set.
+	///
+	/// Deduplicate against a [`SharedSeen`] set in addition to the instance's
+	/// own. Any file or directory claimed by one [`Dowser`] sharing the set
+	/// will be skipped by all the others, now or later, allowing several
+	/// crawlers to cooperatively cover overlapping trees without duplicating
+	/// work.
+	///
+	/// Exclusions added via [`Dowser::without_path`] and kin remain local to
+	/// each instance.
+	///
+	/// Note: roots are claimed as soon as they're added, so this should be
+	/// specified before any with-path(s).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, SharedSeen};
+	/// use std::path::PathBuf;
+	///
+	/// let seen = SharedSeen::default();
+	/// let handles: Vec<_> = ["/usr/share", "/usr/local/share"].into_iter()
+	///     .map(|dir| {
+	///         let crawl = Dowser::default()
+	///             .with_shared_seen(seen.clone())
+	///             .with_path(dir);
+	///         std::thread::spawn(move || crawl.into_vec())
+	///     })
+	///     .collect();
+	///
+	/// // No file will appear more than once across the results.
+	/// let files: Vec<PathBuf> = handles.into_iter()
+	///     .flat_map(|h| h.join().unwrap_or_default())
+	///     .collect();
+	/// ```
+	pub fn with_shared_seen(mut self, seen: SharedSeen) -> Self {
+		self.shared.replace(seen);
+		self
+	}
+
+	#[must_use]
+	/// # Confine To.
+	///
+	/// Restrict the crawl to the given directory trees: any path — root,
+	/// descendant, or symlink target — that does not canonically live under
+	/// one of `roots` will be neither crawled nor yielded.
+	///
+	/// Because the check is applied _after_ canonicalization, a sneaky
+	/// symlink pointing somewhere else entirely — `/etc/shadow`, say — will
+	/// be caught and dropped, making this suitable for sandbox-type work.
+	///
+	/// The confinement roots are themselves canonicalized; any that cannot
+	/// be are ignored. If none can be, _nothing_ will be allowed. Calling
+	/// this more than once adds to the list.
+	///
+	/// Roots queued before this is called are checked on the spot, so call
+	/// order doesn't matter.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let uploads = PathBuf::from("/srv/uploads");
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .confine_to(&[uploads.clone()])
+	///     .with_path(uploads)
+	///     .collect();
+	/// ```
+	pub fn confine_to(mut self, roots: &[PathBuf]) -> Self {
+		let confine = self.confine.get_or_insert_with(Vec::new);
+		confine.extend(roots.iter().filter_map(|p| std::fs::canonicalize(p).ok()));
+
+		// Drop any roots that were already queued but don't belong.
+		let is_confined = |p: &Path| confine.iter().any(|r| p.starts_with(r));
+		self.files.retain(|p| is_confined(p));
+		self.dirs.retain(|(p, _)| is_confined(p));
+		self
+	}
+
+	#[must_use]
+	/// # Without Directory Names.
+	///
+	/// Prevent any directory whose name — final path component — exactly
+	/// matches one of `names` from being crawled, anywhere in the tree.
+	/// This is handy for skipping things like `.git` or `node_modules`
+	/// wholesale.
+	///
+	/// Matching is case-sensitive, and applies to both the directory and,
+	/// if it was reached via a symlink, the link itself. Roots are never
+	/// pruned, as they were explicitly requested.
+	///
+	/// This can be called more than once; the names accumulate.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .without_dir_names(&[".git", "node_modules", "target"])
+	///     .with_path("/my/projects")
+	///     .collect();
+	/// ```
+	pub fn without_dir_names(mut self, names: &[&str]) -> Self {
+		for &name in names {
+			if ! self.dir_names.iter().any(|d| d == name) {
+				self.dir_names.push(OsString::from(name));
+			}
+		}
+		self
+	}
+
+	#[must_use]
+	/// # With Ignore Files.
+	///
+	/// When the crawl reads a directory containing a file named `name` —
+	/// `.dowserignore`, say — each line of that file is treated as a pattern
+	/// of things to skip within that directory's subtree.
+	///
+	/// The rules are deliberately simpler than `.gitignore`:
+	///
+	/// * Blank lines and lines starting with `#` are ignored; everything else is trimmed.
+	/// * Patterns are matched against whole names, not substrings.
+	/// * `*` matches any run of characters and `?` any single character, except `/`. Everything else is literal and case-sensitive.
+	/// * Patterns without a `/` — e.g. `*.log` or `cache/` — are matched against the _name_ of each file or directory, at any depth.
+	/// * Patterns with a `/` — e.g. `/cache` or `docs/*.pdf` — are matched against the _path_ relative to the directory holding the ignore file.
+	/// * Trailing slashes are stripped, and do not limit the pattern to directories.
+	/// * There is no negation; once ignored, always ignored.
+	///
+	/// Ignored directories are pruned wholesale. Rules from nested ignore
+	/// files stack, and never apply outside their own subtree. As with
+	/// [`Dowser::without_dir_names`], entries reached via symlinks are
+	/// tested by both the link and target paths, and roots are never
+	/// ignored.
+	///
+	/// The ignore files themselves are yielded like any other file; add
+	/// their name to the list if that isn't wanted.
+	///
+	/// Note: ignore files are only consulted for directories read from the
+	/// real filesystem, not a custom [source](Dowser::with_source).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_ignore_files(".dowserignore")
+	///     .with_path("/my/projects")
+	///     .collect();
+	/// ```
+	pub fn with_ignore_files(mut self, name: &str) -> Self {
+		self.ignores.set_name(name);
+		self
+	}
+
+	#[must_use]
+	/// # Skip Virtual Filesystems.
+	///
+	/// Prevent any mount points belonging to pseudo filesystems — `/proc`,
+	/// `/sys`, `/dev`, and the like — from being crawled or included in the
+	/// output.
+	///
+	/// These are either bottomless or meaningless as far as file-finding is
+	/// concerned, and can easily bring a crawl of `/` to its knees. Regular
+	/// mounts, including `tmpfs` and network shares, are left alone.
+	///
+	/// Mount points are read from `/proc/self/mounts`, so this only has an
+	/// effect on Linux; elsewhere it is a no-op.
+	///
+	/// Note: like [`Dowser::without_path`], this should be called before any
+	/// with-path(s).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .skip_virtual_filesystems()
+	///     .with_path("/")
+	///     .collect();
+	/// ```
+	pub fn skip_virtual_filesystems(mut self) -> Self {
+		self.seen.extend(virtual_mounts().into_iter().filter_map(|p|
+			std::fs::canonicalize(p).ok().map(|p| Entry::hash_path(&p, true))
+		));
+		self
+	}
+}
+
+impl Dowser {
+	#[must_use]
+	/// # Consume Into Vec.
+	///
+	/// This method is an optimized alternative to running
+	/// `Dowser.iter().collect::<Vec<PathBuf>>()`.
+	///
+	/// It yields the same results as the above, but makes fewer allocations
+	/// along the way.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // The iterator way.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .collect();
+	///
+	/// // The optimized way.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_vec();
+	/// ```
+	///
+	/// Like all of the consuming methods, this picks up wherever the iterator
+	/// left off, so a partially-drained crawl can be finished without
+	/// starting over:
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let mut crawl = Dowser::default().with_path("/usr/share");
+	///
+	/// // Preview the first few.
+	/// let preview: Vec<PathBuf> = crawl.by_ref().take(10).collect();
+	///
+	/// // Grab the rest.
+	/// let rest: Vec<PathBuf> = crawl.into_vec();
+	/// ```
+	pub fn into_vec(mut self) -> Vec<PathBuf> {
+		while let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+
+		// Done!
+		self.files
+	}
+
+	#[must_use]
+	/// # Consume Into Vec (With Capacity).
+	///
+	/// This is the same as [`Dowser::into_vec`], except the output is sized
+	/// up front to hold at least `capacity` paths, sparing a series of
+	/// reallocations when you have a rough idea of how many files the crawl
+	/// will turn up.
+	///
+	/// Note that this is unrelated to [`Dowser::with_capacity`], which sizes
+	/// the crawler's internal bookkeeping.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // It's usually around 100K.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_vec_with_capacity(100_000);
+	/// ```
+	pub fn into_vec_with_capacity(mut self, capacity: usize) -> Vec<PathBuf> {
+		self.files.reserve(capacity.saturating_sub(self.files.len()));
+		self.into_vec()
+	}
+
+	#[must_use]
+	/// # Consume Into Sorted Vec.
+	///
+	/// This is the same as [`Dowser::into_vec`], except the results are
+	/// sorted before being returned, making them deterministic from run to
+	/// run. (Handy for diffing!)
+	///
+	/// If the `rayon` crate feature is enabled, the sort is performed in
+	/// parallel, using the [thread pool](Dowser::with_thread_pool), if any.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_sorted_vec();
+	/// ```
+	pub fn into_sorted_vec(self) -> Vec<PathBuf> {
+		#[cfg(feature = "rayon")]
+		let pool = self.pool.clone();
+
+		let mut out = self.into_vec();
+
+		#[cfg(feature = "rayon")]
+		{
+			use rayon::slice::ParallelSliceMut;
+			match pool {
+				Some(pool) => pool.install(|| out.par_sort_unstable()),
+				None => out.par_sort_unstable(),
+			}
+		}
+
+		#[cfg(not(feature = "rayon"))]
+		out.sort_unstable();
+
+		out
+	}
+
+	#[must_use]
+	/// # Consume Into Vec (Sorted By Key).
+	///
+	/// This is the same as [`Dowser::into_sorted_vec`], except the results
+	/// are ordered by the key returned by the callback — file size, say —
+	/// rather than the paths themselves.
+	///
+	/// The callback is only run once per file, so expensive keys like those
+	/// requiring metadata lookups don't get recomputed during the sort.
+	/// Ties are kept in path order.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::{
+	///     cmp::Reverse,
+	///     path::PathBuf,
+	/// };
+	///
+	/// // The ten biggest files.
+	/// let biggest: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_vec_sorted_by(|p| Reverse(p.metadata().map_or(0, |m| m.len())))
+	///     .into_iter()
+	///     .take(10)
+	///     .collect();
+	/// ```
+	pub fn into_vec_sorted_by<K, F>(self, mut cb: F) -> Vec<PathBuf>
+	where K: Ord, F: FnMut(&Path) -> K {
+		// Pair each path with its key, sort the pairs — ties falling back to
+		// the paths — then strip the keys back off.
+		let mut out: Vec<(K, PathBuf)> = self.into_vec()
+			.into_iter()
+			.map(|p| (cb(&p), p))
+			.collect();
+		out.sort_unstable();
+		out.into_iter().map(|(_, p)| p).collect()
+	}
+
+	#[must_use]
+	/// # Consume Into Boxed Slice.
+	///
+	/// This is the same as [`Dowser::into_vec`], except the results are
+	/// shrunk to fit and returned as a boxed slice.
+	///
+	/// Crawl result sizes are unpredictable, so the underlying `Vec` can wind
+	/// up with a good deal of spare capacity; if you're planning to hold onto
+	/// the results for a while, this reclaims it.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let files: Box<[PathBuf]> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_boxed_slice();
+	/// ```
+	pub fn into_boxed_slice(self) -> Box<[PathBuf]> {
+		self.into_vec().into_boxed_slice()
+	}
+
+	#[must_use]
+	/// # Crawl Into Any Collection.
+	///
+	/// Collect the results directly into any container implementing
+	/// [`FromIterator<PathBuf>`], such as a `BTreeSet` or `HashSet`.
+	///
+	/// This is equivalent to calling [`Iterator::collect`], but spares you
+	/// the turbofish and makes the intent — finish the crawl — explicit.
+	/// Paths are streamed into the container as they're found, with no
+	/// intermediate `Vec`.
+	///
+	/// If you actually want a `Vec`, [`Dowser::into_vec`] is faster.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::{
+	///     collections::BTreeSet,
+	///     path::PathBuf,
+	/// };
+	///
+	/// let files: BTreeSet<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .crawl_into();
+	/// ```
+	pub fn crawl_into<C>(self) -> C
+	where C: FromIterator<PathBuf> { self.collect() }
+
+	/// # With Parsed Extensions.
+	///
+	/// Convert the crawler into an iterator yielding each file path alongside
+	/// its parsed [`Extension`], or `None` if it doesn't have one
+	/// `Extension` can represent.
+	///
+	/// The extension is worked out once, as each file is found, the same way
+	/// as [`Extension::slice_exts`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, Extension};
+	///
+	/// const JPG: Extension = Extension::new3(*b"jpg");
+	///
+	/// for (p, ext) in Dowser::default().with_path("/usr/share").with_parsed_extensions() {
+	///     if ext == Some(JPG) { println!("Image: {}", p.display()); }
+	/// }
+	/// ```
+	pub fn with_parsed_extensions(self)
+	-> impl Iterator<Item=(PathBuf, Option<Extension>)> {
+		self.map(|p| {
+			let ext = Extension::slice_exts(p.as_os_str().as_encoded_bytes()).next();
+			(p, ext)
+		})
+	}
+
+	/// # With Forward Slashes.
+	///
+	/// Convert the crawler into an iterator yielding each file path as a
+	/// `String` with forward slashes for separators, regardless of platform,
+	/// suitable for manifests and other output shared across systems.
+	///
+	/// On Unix the separators are already forward slashes, so this is just
+	/// a string conversion. On Windows, backslashes are swapped out, so the
+	/// results should be treated as display/serialization values rather than
+	/// paths to be fed back into the filesystem.
+	///
+	/// Paths that aren't valid UTF-8 are converted lossily.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let manifest: Vec<String> = Dowser::default()
+	///     .with_path("assets")
+	///     .with_forward_slashes()
+	///     .collect();
+	/// ```
+	pub fn with_forward_slashes(self) -> impl Iterator<Item=String> {
+		self.map(|p| {
+			let p = p.into_os_string()
+				.into_string()
+				.unwrap_or_else(|p| p.to_string_lossy().into_owned());
+
+			if std::path::MAIN_SEPARATOR == '/' { p }
+			else { p.replace(std::path::MAIN_SEPARATOR, "/") }
+		})
+	}
+
+	#[must_use]
+	/// # Consume Into Arc Vec.
+	///
+	/// This is the same as [`Dowser::into_vec`], except the results are
+	/// returned as shared `Arc<Path>`s, making them cheap to clone and pass
+	/// around in fan-out pipelines.
+	///
+	/// For a lazy equivalent, just `map` the iterator: `.map(Arc::<Path>::from)`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::{
+	///     path::Path,
+	///     sync::Arc,
+	/// };
+	///
+	/// let files: Vec<Arc<Path>> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_arc_vec();
+	/// ```
+	pub fn into_arc_vec(self) -> Vec<Arc<Path>> {
+		self.into_vec().into_iter().map(Arc::from).collect()
+	}
+
+	#[must_use]
+	/// # Consume Into `OsString` Vec.
+	///
+	/// This is the same as [`Dowser::into_vec`], except the results are
+	/// returned as `OsString`s, for callers headed that way anyway.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::ffi::OsString;
+	///
+	/// let files: Vec<OsString> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_os_strings();
+	/// ```
+	pub fn into_os_strings(self) -> Vec<OsString> {
+		self.into_vec().into_iter().map(PathBuf::into_os_string).collect()
+	}
+
+	#[must_use]
+	/// # Consume Into Vec (Filtered).
+	///
+	/// This method is an optimized alternative to running
+	/// `Dowser.iter().filter(…).collect::<Vec<PathBuf>>()`.
+	///
+	/// It yields the same results as the above, but makes fewer allocations
+	/// along the way.
+	///
+	/// Note: every entry passed to your callback will be a valid, canonical
+	/// file path. (You don't have to explicitly test for [`is_file`](std::path::Path::is_file) or
+	/// anything like that.)
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // The iterator way.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .filter(|p|
+	///         p.extension().map_or(
+    ///             false,
+    ///             |e| e.eq_ignore_ascii_case("jpg")
+    ///         )
+	///     )
+	///     .collect();
+	///
+	/// // The optimized way.
+	/// let files: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_vec_filtered(|p|
+	///         p.extension().map_or(
+    ///             false,
+    ///             |e| e.eq_ignore_ascii_case("jpg")
+    ///         )
+	///     );
+	/// ```
+	pub fn into_vec_filtered<F>(mut self, cb: F) -> Vec<PathBuf>
+	where F: Fn(&Path) -> bool + Sync + Send {
+		let mut out = Vec::with_capacity(self.files.len());
+		loop {
+			// Filter whatever files we've got so far.
+			out.extend(self.files.drain(..).filter(|p| cb(p)));
+
+			// Read the next directory, if any.
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+			else if self.files.is_empty() { break; }
+		}
+
+		// Done!
+		out
+	}
+
+	#[must_use]
+	/// # Consume Into Vecs (Partitioned).
+	///
+	/// This method crawls the paths, splitting the files into two vectors:
+	/// those for which the callback returned `true`, and those for which it
+	/// returned `false`.
+	///
+	/// It works like [`Iterator::partition`], but with the fewer allocations
+	/// of [`Dowser::into_vec_filtered`].
+	///
+	/// As always, directories are traversed, but not included in the output.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{
+	///     Dowser,
+	///     Extension,
+	/// };
+	/// use std::path::PathBuf;
+	///
+	/// const EXT: Extension = Extension::new3(*b"jpg");
+	///
+	/// let (jpgs, others) = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .partition(|p| EXT == p);
+	/// ```
+	pub fn partition<F>(mut self, cb: F) -> (Vec<PathBuf>, Vec<PathBuf>)
+	where F: Fn(&Path) -> bool + Sync + Send {
+		let mut yes = Vec::new();
+		let mut no = Vec::new();
+		loop {
+			// Sort whatever files we've got so far.
+			for p in self.files.drain(..) {
+				if cb(&p) { yes.push(p); }
+				else { no.push(p); }
+			}
+
+			// Read the next directory, if any.
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+			else if self.files.is_empty() { break; }
+		}
+
+		// Done!
+		(yes, no)
+	}
+
+	#[must_use]
+	/// # Consume Into Vecs (Directories and Files).
+	///
+	/// This method crawls the paths, returning every unique directory it
+	/// traversed along with every file it found, in that order.
+	///
+	/// Both sets are canonical and deduplicated, making this a good starting
+	/// point for tree-mirroring operations.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let (dirs, files) = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .split();
+	/// ```
+	pub fn split(mut self) -> (Vec<PathBuf>, Vec<PathBuf>) {
+		let mut dirs = Vec::new();
+		while let Some((p, depth)) = self.pop_dir() {
+			self.read_dir(&p, depth);
+			if self.is_wanted_dir(&p) { dirs.push(p); }
+		}
+
+		// Done!
+		(dirs, self.files)
+	}
+
+	#[must_use]
+	/// # Total Size.
+	///
+	/// This method crawls the paths, returning the total number of files
+	/// found and their combined size in bytes, without holding onto the
+	/// paths themselves.
+	///
+	/// Only regular files contribute to the byte total; directory sizes are
+	/// not included.
+	///
+	/// Note: the crawl itself only needs to know what _type_ of thing each
+	/// entry is — which usually comes free with the directory listing — so
+	/// sizes are not collected along the way. Each file is `stat`ed once, by
+	/// path, after it is found.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let (files, bytes) = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .total_size();
+	///
+	/// println!("{files} files, {bytes} bytes.");
+	/// ```
+	pub fn total_size(mut self) -> (u64, u64) {
+		let mut files = 0_u64;
+		let mut bytes = 0_u64;
+		loop {
+			// Tally whatever files we've got so far.
+			for p in self.files.drain(..) {
+				files += 1;
+				if let Ok(meta) = std::fs::metadata(&p) {
+					if meta.is_file() { bytes = bytes.saturating_add(meta.len()); }
+				}
+			}
+
+			// Read the next directory, if any.
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+			else if self.files.is_empty() { break; }
+		}
+
+		// Done!
+		(files, bytes)
+	}
+
+	#[must_use]
+	/// # Size Histogram.
+	///
+	/// This method crawls the paths, returning a count of the (regular)
+	/// files found in each of a fixed set of size ranges, without holding
+	/// onto the paths themselves.
+	///
+	/// The buckets grow by powers of four, starting from one kibibyte:
+	///
+	/// | Index | Size |
+	/// | ----- | ---- |
+	/// | 0 | < 1 KiB |
+	/// | 1 | < 4 KiB |
+	/// | 2 | < 16 KiB |
+	/// | 3 | < 64 KiB |
+	/// | 4 | < 256 KiB |
+	/// | 5 | < 1 MiB |
+	/// | 6 | < 4 MiB |
+	/// | 7 | < 16 MiB |
+	/// | 8 | < 64 MiB |
+	/// | 9 | < 256 MiB |
+	/// | 10 | < 1 GiB |
+	/// | 11 | < 4 GiB |
+	/// | 12 | >= 4 GiB |
+	///
+	/// Files whose metadata cannot be read are skipped.
+	///
+	/// As with [`Dowser::total_size`], this costs one extra `stat` per file,
+	/// as the crawl doesn't otherwise look at sizes.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let hist = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .size_histogram();
+	///
+	/// println!("{} files are smaller than 1 KiB.", hist[0]);
+	/// ```
+	pub fn size_histogram(mut self) -> [u64; Self::SIZE_BUCKETS] {
+		let mut out = [0_u64; Self::SIZE_BUCKETS];
+		loop {
+			// Tally whatever files we've got so far.
+			for p in self.files.drain(..) {
+				if let Ok(meta) = std::fs::metadata(&p) {
+					if meta.is_file() {
+						if let Some(v) = out.get_mut(size_bucket(meta.len())) { *v += 1; }
+					}
+				}
+			}
+
+			// Read the next directory, if any.
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+			else if self.files.is_empty() { break; }
+		}
+
+		// Done!
+		out
+	}
+
+	#[must_use]
+	/// # Is Empty Result?
+	///
+	/// Returns `true` if the crawl would not yield any files at all.
+	///
+	/// Unlike `into_vec().is_empty()`, this stops reading directories the
+	/// moment the first file turns up, making it a cheap guard for skipping
+	/// work when there's nothing to do.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// if Dowser::default().with_path("/srv/inbox").is_empty_result() {
+	///     println!("Nothing to process!");
+	/// }
+	/// ```
+	pub fn is_empty_result(mut self) -> bool { self.next().is_none() }
+
+	#[must_use]
+	/// # Consume Into Entries (Filtered).
+	///
+	/// This works like [`Dowser::into_vec_filtered`], except each file is
+	/// stat'd once up front and the callback receives its [`Metadata`](std::fs::Metadata)
+	/// alongside the path. The matches are returned _with_ their metadata
+	/// so that size- or time-based post-processing doesn't have to hit the
+	/// filesystem a second time.
+	///
+	/// Files whose metadata cannot be read are silently dropped.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// // Big files, biggest first.
+	/// let mut big = Dowser::default()
+	///     .with_path("/usr/share")
+	///     .into_entries_filtered(|_, m| 1_000_000 < m.len());
+	/// big.sort_unstable_by(|a, b| b.1.len().cmp(&a.1.len()));
+	/// ```
+	pub fn into_entries_filtered<F>(mut self, cb: F) -> Vec<(PathBuf, std::fs::Metadata)>
+	where F: Fn(&Path, &std::fs::Metadata) -> bool + Sync + Send {
+		let mut out = Vec::with_capacity(self.files.len());
+		loop {
+			// Filter whatever files we've got so far.
+			for p in self.files.drain(..) {
+				if let Ok(meta) = std::fs::metadata(&p) {
+					if cb(&p, &meta) { out.push((p, meta)); }
+				}
+			}
+
+			// Read the next directory, if any.
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+			else if self.files.is_empty() { break; }
+		}
+
+		// Done!
+		out
+	}
+
+	#[must_use]
+	/// # Cross-Root Duplicates.
+	///
+	/// This method crawls each root — path passed to [`Dowser::with_path`]
+	/// and kin — _independently_, returning only the (canonical) file paths
+	/// reachable from more than one of them.
+	///
+	/// This can be useful for figuring out what is shared between two
+	/// otherwise separate directory trees.
+	///
+	/// Note: this is intended for fresh instances; if the iterator has
+	/// already been partially consumed, any pending directories will be
+	/// treated as roots.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // Find the files linked into both.
+	/// let shared: Vec<PathBuf> = Dowser::default()
+	///     .with_paths(["/srv/site-a", "/srv/site-b"])
+	///     .cross_root_duplicates();
+	/// ```
+	pub fn cross_root_duplicates(self) -> Vec<PathBuf> {
+		let mut found: HashMap<u64, (usize, bool), NoHash> = HashMap::default();
+		let mut out = Vec::new();
+		self.crawl_per_root(|idx, _, p| {
+			match found.entry(Entry::hash_path(&p, false)) {
+				MapEntry::Vacant(e) => { e.insert((idx, false)); },
+				MapEntry::Occupied(mut e) => {
+					let (first, dupe) = e.get_mut();
+					if *first != idx && ! *dupe {
+						*dupe = true;
+						out.push(p);
+					}
+				},
+			}
+		});
+
+		out
+	}
+
+	#[must_use]
+	/// # With All Origins.
+	///
+	/// This method crawls each root — path passed to [`Dowser::with_path`]
+	/// and kin — _independently_, returning every unique file found along
+	/// with _all_ the roots it was reachable from.
+	///
+	/// Roots are returned in their canonical form, as that is how they are
+	/// crawled. Invalid roots, and duplicates of roots already queued, never
+	/// make it into the crawl, so won't appear in the results.
+	///
+	/// This answers the question "which inputs contain this file?" for
+	/// overlapping or cross-linked trees. See also
+	/// [`Dowser::cross_root_duplicates`] if you only need the shared files.
+	///
+	/// Note: this is intended for fresh instances; if the iterator has
+	/// already been partially consumed, any pending directories will be
+	/// treated as roots.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let roots = ["/srv/site-a", "/srv/site-b", "/srv/site-c"];
+	/// let found = Dowser::default()
+	///     .with_paths(roots)
+	///     .with_all_origins();
+	///
+	/// for (file, origins) in found {
+	///     if 1 < origins.len() {
+	///         println!("{} is shared by {origins:?}.", file.display());
+	///     }
+	/// }
+	/// ```
+	pub fn with_all_origins(self) -> Vec<(PathBuf, Vec<PathBuf>)> {
+		let mut found: HashMap<u64, usize, NoHash> = HashMap::default();
+		let mut out: Vec<(PathBuf, Vec<PathBuf>)> = Vec::new();
+		self.crawl_per_root(|_, root, p| {
+			match found.entry(Entry::hash_path(&p, false)) {
+				MapEntry::Vacant(e) => {
+					e.insert(out.len());
+					out.push((p, vec![root.to_path_buf()]));
+				},
+				MapEntry::Occupied(e) => if let Some((_, origins)) = out.get_mut(*e.get()) {
+					if origins.last().is_none_or(|last| last != root) {
+						origins.push(root.to_path_buf());
+					}
+				},
+			}
+		});
+
+		out
+	}
+
+	#[must_use]
+	/// # Difference.
+	///
+	/// Crawl both `self` and `other` — separately — returning the files
+	/// found by `self` that were _not_ found by `other`.
+	///
+	/// Because all results are canonical, this works correctly even when
+	/// the two crawls reach the same files by different (symlinked) routes.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // What's in the staging area that hasn't been published yet?
+	/// let pending: Vec<PathBuf> = Dowser::from("/srv/staging")
+	///     .difference(Dowser::from("/srv/public"));
+	/// ```
+	pub fn difference(self, other: Self) -> Vec<PathBuf> {
+		let other = other.into_hashes();
+		self.into_vec_filtered(|p| ! other.contains(&Entry::hash_path(p, false)))
+	}
+
+	#[must_use]
+	/// # Intersection.
+	///
+	/// Crawl both `self` and `other` — separately — returning the files
+	/// found by both.
+	///
+	/// Because all results are canonical, this works correctly even when
+	/// the two crawls reach the same files by different (symlinked) routes.
+	///
+	/// See also [`Dowser::cross_root_duplicates`], which does something
+	/// similar for any number of roots within a single instance.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let shared: Vec<PathBuf> = Dowser::from("/srv/site-a")
+	///     .intersection(Dowser::from("/srv/site-b"));
+	/// ```
+	pub fn intersection(self, other: Self) -> Vec<PathBuf> {
+		let other = other.into_hashes();
+		self.into_vec_filtered(|p| other.contains(&Entry::hash_path(p, false)))
+	}
+
+	#[must_use]
+	/// # Dedup By Relative Name.
+	///
+	/// This method crawls each root — path passed to [`Dowser::with_path`]
+	/// and kin — _independently_, keeping only the first file found for any
+	/// given path _relative to its root_.
+	///
+	/// This is useful for overlay-style merges, where the same logical file
+	/// might exist under several roots, and only the topmost copy matters.
+	/// Directory roots take precedence in the order they were added, so
+	/// list the "upper" layers first.
+	///
+	/// File roots — which are processed before any directories — are keyed
+	/// by their file name, while any files that resolve to a location
+	/// _outside_ their root (via symlinks) are keyed by their full canonical
+	/// path.
+	///
+	/// Note: this is intended for fresh instances; if the iterator has
+	/// already been partially consumed, any pending directories will be
+	/// treated as roots.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// // The effective contents of upper-over-lower.
+	/// let merged: Vec<PathBuf> = Dowser::default()
+	///     .with_paths(["/mnt/upper", "/mnt/lower"])
+	///     .dedup_by_relative_name();
+	/// ```
+	pub fn dedup_by_relative_name(self) -> Vec<PathBuf> {
+		let mut found: HashSet<u64, NoHash> = HashSet::default();
+		let mut out = Vec::new();
+		self.crawl_per_root(|_, root, p| {
+			let rel =
+				if root == p { p.file_name().map(Path::new) }
+				else { p.strip_prefix(root).ok() };
+
+			if found.insert(Entry::hash_path(rel.unwrap_or(&p), false)) { out.push(p); }
+		});
+
+		out
+	}
+
+	#[must_use]
+	/// # Symlinks Only.
+	///
+	/// This method crawls the paths _without_ following symlinks, returning
+	/// the `(link, target)` pairs for every symlink encountered along the
+	/// way instead of any files.
+	///
+	/// Link paths are as-found; targets are canonical. Broken links are
+	/// omitted, as are any roots that were themselves symlinks (as those
+	/// will have already been resolved).
+	///
+	/// This is equivalent to enabling [`Dowser::without_symlinks`] and
+	/// [`Dowser::with_symlink_report`], crawling, and discarding the files.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// for (link, target) in Dowser::from("/etc").symlinks_only() {
+	///     println!("{} -> {}", link.display(), target.display());
+	/// }
+	/// ```
+	pub fn symlinks_only(self) -> Vec<(PathBuf, PathBuf)> {
+		let mut crawl = self.without_symlinks().with_symlink_report();
+		loop {
+			// We don't care about the files.
+			crawl.files.clear();
+
+			// Read the next directory, if any.
+			if let Some((p, depth)) = crawl.pop_dir() { crawl.read_dir(&p, depth); }
+			else { break; }
+		}
+
+		crawl.symlink_report.unwrap_or_default()
+	}
+
+	#[must_use]
+	/// # Deepest File.
+	///
+	/// This method crawls the paths, returning the file found at the
+	/// greatest depth — relative to the root that led to it — along with
+	/// that depth. File roots are depth zero, their directory counterparts'
+	/// immediate children depth one, and so on.
+	///
+	/// This can be useful for spotting pathologically nested trees or
+	/// runaway symlink chains. Ties are resolved arbitrarily.
+	///
+	/// Returns `None` if no files were found.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// if let Some((depth, path)) = Dowser::from("/usr/share").deepest() {
+	///     println!("{} is {depth} levels down.", path.display());
+	/// }
+	/// ```
+	pub fn deepest(mut self) -> Option<(usize, PathBuf)> {
+		// Start tracking file depths. Anything already buffered is presumed
+		// to be a root.
+		self.file_depths = Some(vec![0; self.files.len()]);
+
+		// Depths are only meaningful with all roots in hand.
+		self.resolve_lazy();
+
+		let mut out: Option<(usize, PathBuf)> = None;
+		loop {
+			// Check the files found so far. Their depths usually match, but
+			// not always; list files, for example, add roots.
+			if let Some(depths) = &mut self.file_depths {
+				for (p, depth) in self.files.drain(..).zip(depths.drain(..)) {
+					if out.as_ref().is_none_or(|(d, _)| *d < depth) { out = Some((depth, p)); }
+				}
+			}
+
+			// Read the next directory, if any. Deferred roots can add files
+			// without adding directories, so those have to be checked too.
+			if let Some((p, d)) = self.pop_dir() { self.read_dir(&p, d); }
+			else if self.files.is_empty() { break; }
+		}
+
+		out
+	}
+
+	#[must_use]
+	/// # Only Empty Files.
+	///
+	/// This method crawls the paths, returning only the (regular) files
+	/// that are zero bytes in size.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let empty: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/tmp")
+	///     .only_empty_files();
+	/// ```
+	pub fn only_empty_files(self) -> Vec<PathBuf> {
+		self.into_vec_filtered(|p|
+			std::fs::metadata(p).is_ok_and(|m| m.is_file() && m.len() == 0)
+		)
+	}
+
+	#[must_use]
+	/// # Only Empty Directories.
+	///
+	/// This method crawls the paths, returning only the directories that
+	/// contain nothing at all.
+	///
+	/// Directories that cannot be read are not considered empty.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::path::PathBuf;
+	///
+	/// let empty: Vec<PathBuf> = Dowser::default()
+	///     .with_path("/tmp")
+	///     .only_empty_dirs();
+	/// ```
+	pub fn only_empty_dirs(mut self) -> Vec<PathBuf> {
+		let mut out = Vec::new();
+		while let Some((p, depth)) = self.pop_dir() {
+			if self.read_dir(&p, depth) == Some(0) && self.is_wanted_dir(&p) {
+				out.push(p);
+			}
+
+			// We don't need the files.
+			self.files.clear();
+		}
+
+		// Done!
+		out
+	}
+
+	/// # For Each (Opened) File.
+	///
+	/// Crawl the paths, passing each file path — along with a freshly-opened,
+	/// read-only handle to same — to the callback as soon as it is found.
+	///
+	/// This saves having to re-open every file by path after the fact, which
+	/// can be a big help on slower (e.g. network) filesystems.
+	///
+	/// Files that cannot be opened are passed to the second callback instead,
+	/// along with the error.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use std::io::Read;
+	///
+	/// Dowser::default()
+	///     .with_path("/usr/share")
+	///     .for_each_open(
+	///         |path, mut file| {
+	///             let mut buf = [0_u8; 4];
+	///             if file.read_exact(&mut buf).is_ok() && buf == *b"\x7fELF" {
+	///                 println!("{} is an ELF.", path.display());
+	///             }
+	///         },
+	///         |path, err| eprintln!("Unable to open {}: {err}", path.display()),
+	///     );
+	/// ```
+	pub fn for_each_open<F, E>(mut self, mut cb: F, mut err: E)
+	where F: FnMut(&Path, File), E: FnMut(&Path, std::io::Error) {
+		loop {
+			// Open whatever files we've got so far.
+			for p in self.files.drain(..) {
+				match File::open(&p) {
+					Ok(file) => { cb(&p, file); },
+					Err(e) => { err(&p, e); },
+				}
+			}
+
+			// Read the next directory, if any.
+			if let Some((p, depth)) = self.pop_dir() { self.read_dir(&p, depth); }
+			else if self.files.is_empty() { break; }
+		}
+	}
+
+	/// # For Each (Bounded).
+	///
+	/// Crawl the paths in a background thread, passing each file found to
+	/// the callback — on the current thread — as soon as it is received.
+	///
+	/// The two are connected by a channel holding at most `cap` paths. If
+	/// the callback falls behind, the crawl simply waits for it to catch up,
+	/// keeping memory usage in check when downstream work (hashing,
+	/// uploading, etc.) is the bottleneck.
+	///
+	/// A `cap` of zero is allowed, in which case each path is handed off
+	/// directly, one at a time.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// Dowser::default()
+	///     .with_path("/usr/share")
+	///     .for_each_bounded(1024, |path| {
+	///         // Something slow…
+	///         println!("{}", path.display());
+	///     });
+	/// ```
+	pub fn for_each_bounded<F>(self, cap: usize, mut cb: F)
+	where F: FnMut(PathBuf) {
+		let (tx, rx) = std::sync::mpsc::sync_channel(cap);
+		std::thread::scope(|s| {
+			s.spawn(move || {
+				for p in self {
+					// The receiver is gone; no sense continuing.
+					if tx.send(p).is_err() { break; }
+				}
+			});
+
+			for p in rx { cb(p); }
+		});
+	}
+
+	/// # Walk.
+	///
+	/// Crawl the queued paths, passing each directory to
+	/// [`Visitor::visit_dir`] before reading it, and each file to
+	/// [`Visitor::visit_file`].
+	///
+	/// The crawl stops early if the visitor returns
+	/// [`ControlFlow::Break`](std::ops::ControlFlow::Break).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{Dowser, Visitor};
+	/// use std::{
+	///     ops::ControlFlow,
+	///     path::Path,
+	/// };
+	///
+	/// /// # Count Files.
+	/// struct Counter(usize);
+	///
+	/// impl Visitor for Counter {
+	///     fn visit_file(&mut self, _path: &Path) -> ControlFlow<()> {
+	///         self.0 += 1;
+	///         ControlFlow::Continue(())
+	///     }
+	/// }
+	///
+	/// let mut counter = Counter(0);
+	/// Dowser::default().with_path("/usr/share").walk(&mut counter);
+	/// println!("Found {} files.", counter.0);
+	/// ```
+	pub fn walk<V: Visitor + ?Sized>(mut self, v: &mut V) {
+		loop {
+			for p in self.files.drain(..) {
+				if v.visit_file(&p).is_break() { return; }
+			}
+
+			if let Some((p, depth)) = self.pop_dir() {
+				if v.visit_dir(&p) { self.read_dir(&p, depth); }
+			}
+			else if self.files.is_empty() { break; }
+		}
+	}
+}
+
+impl Dowser {
+	/// # Claim Hash.
+	///
+	/// Record the hash in the `seen` set — and the shared set, if any —
+	/// returning `true` if it was new to both (and the parallel baseline).
+	fn claim(&mut self, hash: u64) -> bool {
+		#[cfg(feature = "rayon")]
+		if self.baseline.as_ref().is_some_and(|b| b.contains(&hash)) { return false; }
+
+		self.seen.insert(hash) && self.shared.as_ref().is_none_or(|s| s.insert(hash))
+	}
+
+	/// # Push Entry.
+	///
+	/// Queue up a resolved entry — directories for crawling, files for
+	/// yielding — unless it has already been seen.
+	///
+	/// The depth is relative to the root that led to it; roots themselves
+	/// are zero.
+	///
+	/// Returns `true` if the entry was new.
+	fn push(&mut self, e: Entry, depth: usize) -> bool {
+		if
+			self.is_confined(&e.path) &&
+			! self.is_excluded(&e.path) &&
+			self.claim(e.hash)
+		{
+			if e.is_dir && ! self.is_bundle(&e.path, depth) {
+				// Nothing inside could be shallow enough.
+				if self.max_depth <= depth {}
+				else if depth <= self.depth_ceiling { self.push_dir(e.path, depth); }
+				else {
+					trace_event!(debug, path = %e.path.display(), depth, "directory too deep");
+					self.too_deep.push(e.path);
+				}
+			}
+			else if self.min_depth <= depth && depth <= self.max_depth {
+				if e.is_dir {
+					if self.is_wanted(&e.path) { self.buffer_file(e.path, depth); }
+				}
+				else { self.push_file(e.path, depth); }
+			}
+			true
+		}
+		else { false }
+	}
+
+	/// # Pop Directory.
+	///
+	/// Return the next directory to read, if any, pulling in lazy roots as
+	/// needed to find one. (Any file roots pulled along the way are queued
+	/// as usual, so callers should check `files` before giving up.)
+	fn pop_dir(&mut self) -> Option<(PathBuf, usize)> {
+		while self.dirs.is_empty() {
+			if let Some(path) = self.deferred.pop() {
+				if let Some(e) = Entry::from_path(path) { self.push_root(e); }
+				continue;
+			}
+
+			let Some(path) = self.lazy.as_ref().and_then(LazyPaths::next) else {
+				self.lazy = None;
+				break;
+			};
+			self.push_path(&path);
+		}
+
+		self.dirs.pop_back()
+	}
+
+	/// # Push Directory.
+	///
+	/// Queue up a directory for reading, at the back for depth-first
+	/// traversal, or the front for breadth-first.
+	fn push_dir(&mut self, path: PathBuf, depth: usize) {
+		match self.order {
+			Order::DepthFirst => { self.dirs.push_back((path, depth)); },
+			Order::BreadthFirst => { self.dirs.push_front((path, depth)); },
+		}
+	}
+
+	/// # Resolve Lazy Roots.
+	///
+	/// Pull in all remaining deferred and lazy roots at once.
+	fn resolve_lazy(&mut self) {
+		for path in std::mem::take(&mut self.deferred) {
+			if let Some(e) = Entry::from_path(path) { self.push_root(e); }
+		}
+
+		if let Some(lazy) = self.lazy.take() {
+			while let Some(path) = lazy.next() { self.push_path(&path); }
+		}
+	}
+
+	/// # Queue Path.
+	///
+	/// Queue up a root path for resolution now, or later if deferring.
+	fn queue_path(&mut self, path: &Path) {
+		if self.defer_roots {
+			let path = rebase(self.base.as_deref(), path).into_owned();
+			self.deferred.push(path);
+		}
+		else { self.push_path(path); }
+	}
+
+	/// # Push Path.
+	///
+	/// Resolve and queue up a root path, if valid.
+	fn push_path(&mut self, path: &Path) {
+		let path = rebase(self.base.as_deref(), path);
+		if let Some(e) = Entry::from_path(path) { self.push_root(e); }
+	}
+
+	/// # Push Root.
+	///
+	/// Queue up a resolved root entry, provided it passes the root filter,
+	/// if any.
+	fn push_root(&mut self, e: Entry) {
+		if
+			self.root_kind_error(e.is_dir).is_none() &&
+			self.root_filter.as_ref().is_none_or(|cb| cb.call(&e.path))
+		{
+			if self.nlink_hint && e.is_dir { self.reserve_nlink(&e.path); }
+			self.push(e, 0);
+		}
+	}
+
+	#[cfg(unix)]
+	/// # Reserve From Link Count.
+	///
+	/// Grow the directory queue and seen set by the (estimated) number of
+	/// subdirectories inside `dir`, per its hard link count.
+	///
+	/// The estimate is capped to keep a bogus count from triggering a
+	/// massive allocation.
+	fn reserve_nlink(&mut self, dir: &Path) {
+		use std::os::unix::fs::MetadataExt;
+
+		/// # Maximum Reservation.
+		const MAX: u64 = 1 << 16;
+
+		let subdirs = std::fs::metadata(dir).map_or(0, |m| m.nlink().saturating_sub(2));
+		let subdirs = usize::try_from(subdirs.min(MAX)).unwrap_or_default();
+		if 0 != subdirs {
+			self.dirs.reserve(subdirs);
+			self.seen.reserve(subdirs);
+		}
+	}
+
+	#[cfg(not(unix))]
+	#[inline]
+	/// # Reserve From Link Count (Noop).
+	///
+	/// Link counts aren't meaningful here, so this does nothing.
+	const fn reserve_nlink(&mut self, _dir: &Path) {}
+
+	/// # Root Kind Error.
+	///
+	/// Return the appropriate error kind if a root is a directory when files
+	/// are required, or vice versa.
+	const fn root_kind_error(&self, is_dir: bool) -> Option<ErrorKind> {
+		match self.root_dirs {
+			Some(true) if ! is_dir => Some(ErrorKind::NotADirectory),
+			Some(false) if is_dir => Some(ErrorKind::IsADirectory),
+			_ => None,
+		}
+	}
+
+	/// # Is Confined?
+	///
+	/// Returns `true` if there are no confinement roots, or the path lives
+	/// under one of them.
+	fn is_confined(&self, path: &Path) -> bool {
+		self.confine.as_ref().is_none_or(|roots| roots.iter().any(|r| path.starts_with(r)))
+	}
+
+	#[cfg(feature = "regex")]
+	/// # Is Excluded?
+	///
+	/// Returns `true` if the path matches any of the exclusion patterns.
+	fn is_excluded(&self, path: &Path) -> bool {
+		#[cfg(unix)]
+		use std::os::unix::ffi::OsStrExt;
+
+		self.exclude.as_ref().is_some_and(|set| {
+			#[cfg(unix)] { set.is_match(path.as_os_str().as_bytes()) }
+			#[cfg(not(unix))] { set.is_match(path.to_string_lossy().as_bytes()) }
+		})
+	}
+
+	#[cfg(not(feature = "regex"))]
+	#[expect(clippy::unused_self, reason = "For consistency.")]
+	#[inline]
+	/// # Is Excluded?
+	///
+	/// Without any exclusion patterns, this is always `false`.
+	const fn is_excluded(&self, _path: &Path) -> bool { false }
+
+	/// # Is Bundle?
+	///
+	/// Returns `true` if the (non-root) directory has one of the bundle
+	/// extensions.
+	fn is_bundle(&self, path: &Path, depth: usize) -> bool {
+		0 != depth &&
+		! self.bundle_exts.is_empty() &&
+		Extension::first_match(path, &self.bundle_exts).is_some()
+	}
+
+	/// # Is Wanted (Directory)?
+	///
+	/// Returns `true` if the directory passes the directory filter, if any.
+	fn is_wanted_dir(&self, path: &Path) -> bool {
+		self.dir_filter.as_ref().is_none_or(|cb| cb.call(path))
+	}
+
+	/// # Is Pruned?
+	///
+	/// Returns `true` if the directory — or the symlink that led to it — has
+	/// one of the pruned names.
+	fn is_pruned(&self, path: &Path, link: Option<&Path>) -> bool {
+		! self.dir_names.is_empty() &&
+		[Some(path), link].into_iter()
+			.flatten()
+			.filter_map(Path::file_name)
+			.any(|n| self.dir_names.iter().any(|d| d == n))
+	}
+
+	#[cfg_attr(not(feature = "archives"), inline)]
+	/// # Push File.
+	///
+	/// Queue up a new (unique) file path for yielding.
+	///
+	/// If the path is a list file, its entries will be queued as roots in
+	/// its place. Likewise, if archive crawling is enabled and the path is a
+	/// supported archive, its members will be queued instead.
+	fn push_file(&mut self, path: PathBuf, depth: usize) {
+		if
+			Extension::first_match(&path, &self.list_exts).is_some() &&
+			self.read_paths_from_file(&path).is_ok()
+		{
+			return;
+		}
+
+		#[cfg(feature = "archives")]
+		if let Some(members) = self.archives.members(&path) {
+			for p in members {
+				if self.claim(Entry::hash_path(&p, false)) && self.is_wanted(&p) {
+					self.buffer_file(p, depth);
+				}
+			}
+			return;
+		}
+
+		if self.is_wanted(&path) { self.buffer_file(path, depth); }
+	}
+
+	/// # Buffer File.
+	///
+	/// Add a file to the output buffer, recording its depth too if we're
+	/// keeping track.
+	fn buffer_file(&mut self, path: PathBuf, depth: usize) {
+		if let Some(depths) = &mut self.file_depths { depths.push(depth); }
+		self.files.push(path);
+	}
+
+	/// # Is Wanted?
+	///
+	/// Returns `true` if the file passes the filter, if any.
+	fn is_wanted(&self, path: &Path) -> bool {
+		self.filter.as_ref().is_none_or(|cb| cb.call(path))
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	#[inline]
+	/// # Read Directory.
+	///
+	/// Read the contents of a directory, queueing up any new entries found
+	/// inside.
+	///
+	/// Returns the total number of entries read — new or not — or `None` if
+	/// the directory could not be read.
+	fn read_dir(&mut self, path: &Path, depth: usize) -> Option<usize> {
+		self.read_dir_raw(path, depth)
+	}
+
+	#[cfg(feature = "tracing")]
+	/// # Read Directory.
+	///
+	/// Read the contents of a directory, queueing up any new entries found
+	/// inside.
+	///
+	/// This version wraps the work in a `tracing` span, recording the entry
+	/// count and elapsed time on the way out.
+	///
+	/// Returns the total number of entries read — new or not — or `None` if
+	/// the directory could not be read.
+	fn read_dir(&mut self, path: &Path, depth: usize) -> Option<usize> {
+		let span = tracing::debug_span!(
+			target: "dowser",
+			"read_dir",
+			path = %path.display(),
+			depth,
+			entries = tracing::field::Empty,
+			elapsed = tracing::field::Empty,
+		).entered();
+
+		let now = Instant::now();
+		let res = self.read_dir_raw(path, depth);
+		if let Some(total) = res { span.record("entries", total); }
+		span.record("elapsed", tracing::field::debug(now.elapsed()));
+		res
+	}
+
+	/// # Retype.
+	///
+	/// Re-resolve a queued directory that is no longer a directory,
+	/// queueing it up as a file if it is one now.
+	fn retype(&mut self, path: &Path, depth: usize) {
+		trace_event!(debug, path = %path.display(), "directory changed type");
+		self.type_changed.push(path.to_path_buf());
+		if let Some(e) = Entry::from_path(path) {
+			if ! e.is_dir { self.push(e, depth); }
+		}
+	}
+
+	/// # Read Directory (Raw).
+	///
+	/// This is the actual implementation of [`Dowser::read_dir`].
+	fn read_dir_raw(&mut self, path: &Path, depth: usize) -> Option<usize> {
+		// Abandon the crawl if we're out of time.
+		if let Some(budget) = self.time_budget {
+			let started = *self.started.get_or_insert_with(Instant::now);
+			if budget <= started.elapsed() {
+				trace_event!(info, ?budget, "time budget exhausted; abandoning crawl");
+				self.dirs.clear();
+				self.deferred.clear();
+				self.lazy = None;
+				return None;
+			}
+		}
+
+		// Use the custom source, if any.
+		if let Some(src) = self.source.clone() {
+			let mut entries = match src.0.read_dir(path) {
+				Ok(entries) => entries,
+				Err(e) => {
+					trace_event!(warn, path = %path.display(), error = %e, "unable to read directory");
+					self.on_read_error(&e);
+					return None;
+				},
+			};
+
+			// Drop the excess, if limited.
+			if let Some(limit) = self.entry_limit {
+				if limit < entries.len() {
+					trace_event!(debug, path = %path.display(), limit, "directory truncated");
+					entries.truncate(limit);
+					self.truncated.push(path.to_path_buf());
+				}
+			}
+
+			let depth = depth.saturating_add(1);
+			let mut subdirs = Vec::new();
+			let total = entries.len();
+			for (path, is_dir) in entries {
+				let hash = Entry::hash_path(&path, is_dir);
+				self.push_child(Entry { path, link: None, is_dir, hash }, depth, &mut subdirs);
+			}
+
+			self.push_subdirs(subdirs, depth);
+			return Some(total);
+		}
+
+		let rd = match self.retry.run(|| std::fs::read_dir(path)) {
+			Ok(rd) => rd,
+			// The directory may have been swapped for a file since it was
+			// queued; if so, give it another go as such.
+			Err(e) if e.kind() == ErrorKind::NotADirectory => {
+				self.retype(path, depth);
+				return None;
+			},
+			Err(e) => {
+				trace_event!(warn, path = %path.display(), error = %e, "unable to read directory");
+				self.on_read_error(&e);
+				if self.retry.is_enabled() && is_transient(&e) {
+					self.failed_reads.push((path.to_path_buf(), Arc::new(e)));
+				}
+				return None;
+			},
+		};
+
+		// Pick up the local ignore rules, if any.
+		self.ignores.load(path);
+
+		// If prefetching, slurp up all the entries now so the handle can be
+		// closed before we get to work on them.
+		// (One extra is read when limited so we can tell if anything was
+		// left over.)
+		let (rd, prefetched) =
+			if self.prefetch {
+				let take = self.entry_limit.map_or(usize::MAX, |l| l.saturating_add(1));
+				(None, rd.take(take).collect::<Vec<_>>())
+			}
+			else { (Some(rd), Vec::new()) };
+
+		let depth = depth.saturating_add(1);
+		let mut subdirs = Vec::new();
+		let mut total = 0;
+		for e in prefetched.into_iter().chain(rd.into_iter().flatten()) {
+			// Stop if we've hit the limit.
+			if self.entry_limit.is_some_and(|l| l <= total) {
+				trace_event!(debug, path = %path.display(), limit = total, "directory truncated");
+				self.truncated.push(path.to_path_buf());
+				break;
+			}
+
+			total += 1;
+
+			// Report the symlinks we aren't following, if requested.
+			if
+				! self.symlinks &&
+				(self.symlink_report.is_some() || self.broken_symlinks.is_some())
+			{
+				if let Ok(e) = &e { self.report_unfollowed(e); }
+			}
+
+			match Entry::from_entry(e, self.symlinks, self.retry, self.symlink_hops) {
+				Ok(Some(e)) => { self.push_child(e, depth, &mut subdirs); },
+				Ok(None) => {},
+				Err((link, err)) => {
+					trace_event!(warn, link = %link.display(), error = %err, "broken symlink");
+					if let Some(report) = &mut self.broken_symlinks {
+						report.push((link, Arc::new(err)));
+					}
+				},
+			}
+		}
+
+		self.push_subdirs(subdirs, depth);
+		Some(total)
+	}
+
+	/// # Report Unfollowed Symlink.
+	///
+	/// If the entry is a symlink, add it to the symlink or broken symlink
+	/// report, as appropriate. This is only used when symlinks aren't being
+	/// followed, as otherwise they're reported during resolution.
+	fn report_unfollowed(&mut self, e: &std::fs::DirEntry) {
+		if e.file_type().is_ok_and(|ft| ft.is_symlink()) {
+			let link = e.path();
+			match std::fs::canonicalize(&link) {
+				Ok(target) => if let Some(report) = &mut self.symlink_report {
+					report.push((link, target));
+				},
+				Err(err) => {
+					trace_event!(warn, link = %link.display(), error = %err, "broken symlink");
+					if let Some(report) = &mut self.broken_symlinks {
+						report.push((link, Arc::new(err)));
+					}
+				},
+			}
+		}
+	}
+
+	/// # Push Child.
+	///
+	/// Queue up an entry found while reading a directory, unless its name
+	/// has been pruned. If subdirectories are being limited, directories are
+	/// added to `subdirs` instead, for later consideration by
+	/// [`Dowser::push_subdirs`].
+	fn push_child(&mut self, mut e: Entry, depth: usize, subdirs: &mut Vec<Entry>) {
+		let link = e.link.take();
+
+		// Skip unwanted directories by name.
+		if e.is_dir && self.is_pruned(&e.path, link.as_deref()) {
+			trace_event!(trace, path = %e.path.display(), "skipping pruned directory");
+			return;
+		}
+
+		// Skip anything ignored by an ignore file.
+		if self.ignores.is_ignored(&e.path, link.as_deref()) {
+			trace_event!(trace, path = %e.path.display(), "skipping ignored path");
+			return;
+		}
+
+		// Skip specifically excluded symlinks.
+		if
+			! self.link_excludes.is_empty() &&
+			link.as_deref().is_some_and(|l| self.link_excludes.contains(&Entry::hash_path(l, false)))
+		{
+			trace_event!(trace, path = %e.path.display(), "skipping excluded symlink");
+			return;
+		}
+
+		if let Some(link) = link {
+			trace_event!(trace, link = %link.display(), target = %e.path.display(), "symlink resolved");
+			if let Some(report) = &mut self.symlink_report {
+				report.push((link, e.path.clone()));
+			}
+		}
+
+		// Hold back the subdirectories if we're limiting them.
+		if e.is_dir && self.dir_limit.is_some() { subdirs.push(e); }
+		else { self.push(e, depth); }
+	}
+
+	/// # Push Subdirectories.
+	///
+	/// Queue up the first few held-back subdirectories, if limited.
+	fn push_subdirs(&mut self, mut subdirs: Vec<Entry>, depth: usize) {
+		if let Some(limit) = self.dir_limit {
+			subdirs.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+			let mut queued = 0;
+			for e in subdirs {
+				if limit <= queued { break; }
+				if self.push(e, depth) { queued += 1; }
+			}
+		}
+	}
+
+	/// # Handle Read Error.
+	///
+	/// Permission errors are handled according to the policy; everything
+	/// else is silently ignored.
+	fn on_read_error(&mut self, e: &std::io::Error) {
+		if e.kind() == ErrorKind::PermissionDenied { self.on_denied(); }
+	}
+
+	/// # Handle Permission Denied.
+	///
+	/// Count the failure and/or abort the crawl, depending on the policy.
+	fn on_denied(&mut self) {
+		match self.permission_policy {
+			PermissionPolicy::Skip => {},
+			PermissionPolicy::Count => { self.permission_denied += 1; },
+			PermissionPolicy::Abort => {
+				self.permission_denied += 1;
+				self.files.clear();
+				if let Some(depths) = &mut self.file_depths { depths.clear(); }
+				self.dirs.clear();
+				self.deferred.clear();
+				self.lazy = None;
+			},
+		}
+	}
+
+	/// # Into Hashes.
+	///
+	/// Crawl the paths, returning the hashes of the files found (rather than
+	/// the files themselves).
+	fn into_hashes(self) -> HashSet<u64, NoHash> {
+		self.map(|p| Entry::hash_path(&p, false)).collect()
+	}
+
+	/// # Crawl Per Root.
+	///
+	/// Crawl each of the queued roots independently — i.e. without sharing
+	/// the `seen` set — passing the root index, the root itself, and each
+	/// file found to the callback.
+	///
+	/// The index is only meaningful for telling roots apart: file roots are
+	/// numbered first, followed by directory roots, and anything dropped
+	/// during resolution isn't counted at all. Any exclusions remain in
+	/// effect for all of them.
+	fn crawl_per_root<F>(mut self, mut cb: F)
+	where F: FnMut(usize, &Path, PathBuf) {
+		// Pull out the roots and strip their hashes from the seen set,
+		// leaving only the exclusions behind. (Lazy roots have to be resolved
+		// first or the clones would split them.)
+		self.resolve_lazy();
+		let files = std::mem::take(&mut self.files);
+		let mut dirs = std::mem::take(&mut self.dirs);
+		let mut seen = std::mem::take(&mut self.seen);
+
+		// Each root gets its own dedup set, so a shared one — which the
+		// first root would fill for everybody else — has to go.
+		self.shared = None;
+
+		// Breadth-first queues run backwards.
+		if self.order == Order::BreadthFirst { dirs.make_contiguous().reverse(); }
+		for p in &files { seen.remove(&Entry::hash_path(p, false)); }
+		for (p, _) in &dirs { seen.remove(&Entry::hash_path(p, true)); }
+
+		let roots = files.into_iter().map(|p| (p, None))
+			.chain(dirs.into_iter().map(|(p, depth)| (p, Some(depth))));
+		for (idx, (p, depth)) in roots.enumerate() {
+			let mut sub = self.clone();
+			sub.seen.clone_from(&seen);
+			sub.seen.insert(Entry::hash_path(&p, depth.is_some()));
+			if let Some(depth) = depth { sub.dirs.push_back((p.clone(), depth)); }
+			else { sub.files.push(p.clone()); }
+
+			for found in sub.into_vec() { cb(idx, &p, found); }
+		}
+	}
+}
+
+
+
+/// # Canonical Link Path.
+///
+/// Canonicalize the _parent_ of a symlink, rejoining it with the link's own
+/// name, matching the form links take during the crawl.
+fn canonical_link(path: &Path) -> Option<PathBuf> {
+	let name = path.file_name()?;
+	let parent = path.parent()
+		.filter(|p| ! p.as_os_str().is_empty())
+		.unwrap_or_else(|| Path::new("."));
+	std::fs::canonicalize(parent).ok().map(|p| p.join(name))
+}
+
+/// # Size Bucket.
+///
+/// Return the [`Dowser::size_histogram`] bucket index for a file size.
+const fn size_bucket(len: u64) -> usize {
+	if len < 1024 { 0 }
+	else {
+		let idx = (len.ilog2() as usize - 10) / 2 + 1;
+		if idx < Dowser::SIZE_BUCKETS { idx }
+		else { Dowser::SIZE_BUCKETS - 1 }
+	}
+}
+
+#[cfg(unix)]
+#[expect(clippy::unnecessary_wraps, reason = "For consistency.")]
+/// # Index Bytes.
+///
+/// Return the raw bytes for a path.
+fn index_bytes(path: &Path) -> Result<&[u8], std::io::Error> {
+	use std::os::unix::ffi::OsStrExt;
+	Ok(path.as_os_str().as_bytes())
+}
+
+#[cfg(not(unix))]
+/// # Index Bytes.
+///
+/// Return the (UTF-8) bytes for a path.
+fn index_bytes(path: &Path) -> Result<&[u8], std::io::Error> {
+	path.to_str().map(str::as_bytes).ok_or_else(|| ErrorKind::InvalidInput.into())
+}
+
+#[cfg(unix)]
+#[expect(clippy::unnecessary_wraps, reason = "For consistency.")]
+/// # Index Path.
+///
+/// Convert raw bytes back into a path.
+fn index_path(raw: Vec<u8>) -> Result<PathBuf, std::io::Error> {
+	use std::os::unix::ffi::OsStringExt;
+	Ok(PathBuf::from(OsString::from_vec(raw)))
+}
+
+#[cfg(not(unix))]
+/// # Index Path.
+///
+/// Convert (UTF-8) bytes back into a path.
+fn index_path(raw: Vec<u8>) -> Result<PathBuf, std::io::Error> {
+	String::from_utf8(raw)
+		.map(PathBuf::from)
+		.map_err(|_| ErrorKind::InvalidData.into())
+}
+
+/// # Rebase Path.
+///
+/// Join relative paths to the base directory, if any. Absolute paths are
+/// returned as-are.
+fn rebase<'a>(base: Option<&Path>, path: &'a Path) -> Cow<'a, Path> {
+	match base {
+		Some(base) if path.is_relative() => Cow::Owned(base.join(path)),
+		_ => Cow::Borrowed(path),
+	}
+}
+
+#[cfg(target_os = "linux")]
+/// # Virtual Mount Points.
+///
+/// Return the mount points of any pseudo filesystems listed in
+/// `/proc/self/mounts`.
+fn virtual_mounts() -> Vec<PathBuf> {
+	std::fs::read_to_string("/proc/self/mounts")
+		.map_or_else(|_| Vec::new(), |raw| parse_virtual_mounts(&raw))
+}
+
+#[cfg(not(target_os = "linux"))]
+#[inline]
+/// # Virtual Mount Points.
+///
+/// This is only supported on Linux.
+const fn virtual_mounts() -> Vec<PathBuf> { Vec::new() }
+
+#[cfg(target_os = "linux")]
+/// # Parse Virtual Mount Points.
+///
+/// Each line of the mount table is formatted like
+/// `device mountpoint fstype options dump pass`, with any whitespace in the
+/// mount point escaped as three-digit octal, e.g. `\040`.
+fn parse_virtual_mounts(raw: &str) -> Vec<PathBuf> {
+	use std::os::unix::ffi::OsStringExt;
+
+	/// # Pseudo Filesystem Types.
+	const VIRTUAL: [&str; 21] = [
+		"autofs", "binfmt_misc", "bpf", "cgroup", "cgroup2", "configfs",
+		"debugfs", "devpts", "devtmpfs", "efivarfs", "fusectl", "hugetlbfs",
+		"mqueue", "nsfs", "proc", "pstore", "rpc_pipefs", "securityfs",
+		"selinuxfs", "sysfs", "tracefs",
+	];
+
+	raw.lines()
+		.filter_map(|line| {
+			let mut parts = line.split_ascii_whitespace().skip(1);
+			let dir = parts.next()?;
+			let kind = parts.next()?;
+			if ! VIRTUAL.contains(&kind) { return None; }
+
+			// Unescape the octal bits.
+			let mut out = Vec::with_capacity(dir.len());
+			let mut bytes = dir.as_bytes();
+			loop {
+				match bytes {
+					[b'\\', a @ b'0'..=b'3', b @ b'0'..=b'7', c @ b'0'..=b'7', rest @ ..] => {
+						out.push(((a - b'0') << 6) | ((b - b'0') << 3) | (c - b'0'));
+						bytes = rest;
+					},
+					[b, rest @ ..] => {
+						out.push(*b);
+						bytes = rest;
+					},
+					[] => break,
+				}
+			}
+
+			Some(PathBuf::from(OsString::from_vec(out)))
+		})
+		.collect()
+}
+
+/// # Is Singular Path?
+///
+/// Returns true if the type seems to be a singular `Path`/`PathBuf` object.
+/// This is necessary to differentiate them from proper collections
+/// implementing `IntoIterator<AsRef<Path>>`, at least until negative trait
+/// bounds are stabilized.
+fn is_singular_path<T>(raw: T) -> bool {
+	/// # Type to String.
+	fn type_of<T>(_: T) -> &'static str { std::any::type_name::<T>() }
+
+	let kind = type_of(raw).trim_start_matches('&');
+	kind == "std::path::Path" || kind == "std::path::PathBuf"
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use brunch as _;
+
+	#[test]
+	fn t_new() {
+		let mut abs_dir = std::fs::canonicalize("tests/assets/").unwrap();
+		abs_dir.push("_.txt");
+		let abs_p1 = abs_dir.with_file_name("file.txt");
+		let abs_p2 = abs_dir.with_file_name("is-executable.sh");
+		let abs_perr = abs_dir.with_file_name("foo.bar");
+
+		// Builder init.
+		let mut w1: Vec<PathBuf> = Dowser::default()
+			.with_path(PathBuf::from("tests/"))
+			.collect();
+		assert!(! w1.is_empty());
+		assert_eq!(w1.len(), 9);
+		assert!(w1.contains(&abs_p1));
+		assert!(w1.contains(&abs_p2));
+		assert!(! w1.contains(&abs_perr));
+
+		// From init.
+		let mut w2: Vec<PathBuf> = Dowser::from("tests/").collect();
+		w1.sort();
+		w2.sort();
+		assert_eq!(w1, w2);
+	}
+
+	#[test]
+	fn t_size_hint() {
+		let mut crawl = Dowser::from("tests/assets/");
+		assert_eq!(crawl.size_hint(), (0, None), "Size should be unknown before crawling.");
+
+		// The first pull reads the one and only directory, so what's left is
+		// known exactly.
+		assert!(crawl.next().is_some());
+		assert_eq!(crawl.size_hint(), (2, Some(2)));
+		assert_eq!(crawl.count(), 2);
+	}
+
+	#[test]
+	fn t_resolve_path() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// Make sure symlinks are detected.
+		let links = std::fs::read_dir(&test_dir)
+			.expect("Missing dowser link directory.")
+			.filter_map(Result::ok)
+			.filter_map(|e| e.file_type().ok())
+			.filter(std::fs::FileType::is_symlink)
+			.count();
+		assert_eq!(links, 1, "Wrong symlink count!");
+
+		let raw = vec![
+			test_dir.join("01"),
+			test_dir.join("02"),
+			test_dir.join("03"),
+			test_dir.join("04"),
+			test_dir.join("05"), // Directory.
+			test_dir.join("06"), // Directory.
+			test_dir.join("07"), // Sym to six.
+			test_dir.join("06/08"),
+			test_dir.join("06/09"),
+			test_dir.join("06/10"), // Sym to one.
+			test_dir.join("06/11"), // Sym to seven to six.
+		];
+
+		let mut canon = {
+			let mut tmp: Vec<PathBuf> = raw.iter()
+				.filter_map(|x| std::fs::canonicalize(x).ok())
+				.collect();
+			tmp.sort();
+			tmp.dedup();
+			tmp
+		};
+
+		// There should be two fewer entries as two are symlinks.
+		assert_eq!(raw.len(), 11);
+		assert_eq!(canon.len(), 8, "{canon:?}");
+		assert!(! canon.contains(&raw[6]));
+		assert!(! canon.contains(&raw[9]));
+		assert!(! canon.contains(&raw[10]));
+
+		let trusting = {
+			let mut tmp: Vec<PathBuf> = raw.iter()
+				.filter_map(Entry::from_path)
+				.map(|e| e.path)
+				.collect();
+			tmp.sort();
+			tmp.dedup();
+			tmp
+		};
+
+		assert_eq!(trusting, canon);
+
+		// Now let's make sure Dowser does the same thing, albeit just the
+		// files.
+		canon.retain(|p| p.is_file());
+
+		let mut itered: Vec<PathBuf> = Dowser::from(test_dir.as_path()).collect();
+		itered.sort();
+		assert_eq!(canon, itered);
+
+		itered = Dowser::from(test_dir.as_path()).into_vec_filtered(|_| true);
+		itered.sort();
+		assert_eq!(canon, itered);
+	}
+
+	#[test]
+	fn t_with_min_depth() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// Zero is the default.
+		let mut all: Vec<PathBuf> = Dowser::from(test_dir.as_path()).collect();
+		all.sort();
+		let mut found: Vec<PathBuf> = Dowser::default()
+			.with_min_depth(0)
+			.with_path(&test_dir)
+			.collect();
+		found.sort();
+		assert_eq!(all, found);
+
+		// Everything is at least one deep.
+		found = Dowser::default()
+			.with_min_depth(1)
+			.with_path(&test_dir)
+			.into_vec();
+		found.sort();
+		assert_eq!(all, found);
+
+		// Only 06/08 and 06/09 are two deep.
+		found = Dowser::default()
+			.with_min_depth(2)
+			.with_path(&test_dir)
+			.into_vec_filtered(|_| true);
+		found.sort();
+		assert_eq!(found, [test_dir.join("06/08"), test_dir.join("06/09")]);
+
+		// And nothing is three deep.
+		assert!(Dowser::default().with_min_depth(3).with_path(&test_dir).next().is_none());
+
+		// File roots are zero deep.
+		assert!(Dowser::default().with_min_depth(1).with_path(test_dir.join("01")).next().is_none());
+
+		// Even if they were added first.
+		assert!(Dowser::from(test_dir.join("01")).with_min_depth(1).next().is_none());
+	}
+
+	#[test]
+	fn t_with_max_depth() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// Everything but 06/08 and 06/09 is one deep.
+		let mut found = Dowser::default()
+			.with_max_depth(1)
+			.with_path(&test_dir)
+			.into_vec();
+		found.sort();
+		assert_eq!(found, [
+			test_dir.join("01"),
+			test_dir.join("02"),
+			test_dir.join("03"),
+			test_dir.join("04"),
+		]);
+
+		// Zero allows file roots, but nothing from directories.
+		let found = Dowser::default()
+			.with_max_depth(0)
+			.with_paths([test_dir.join("01"), test_dir.clone()])
+			.into_vec();
+		assert_eq!(found, [test_dir.join("01")]);
+
+		// A band.
+		let mut found = Dowser::default()
+			.with_min_depth(2)
+			.with_max_depth(2)
+			.with_path(&test_dir)
+			.into_vec();
+		found.sort();
+		assert_eq!(found, [test_dir.join("06/08"), test_dir.join("06/09")]);
+
+		// An impossible band.
+		assert!(
+			Dowser::default()
+				.with_min_depth(2)
+				.with_max_depth(1)
+				.with_path(&test_dir)
+				.next()
+				.is_none()
+		);
+	}
+
+	#[test]
+	fn t_into_sorted_vec() {
+		let mut all = Dowser::from("tests/").into_vec();
+		all.sort();
+		assert_eq!(all.len(), 9);
+		assert_eq!(all, Dowser::from("tests/").into_sorted_vec());
+	}
+
+	#[test]
+	fn t_into_vec_sorted_by() {
+		use std::cmp::Reverse;
+
+		let test_dir = std::fs::canonicalize("./tests/assets")
+			.expect("Missing dowser test directory.");
+
+		// Biggest first.
+		let found = Dowser::from(test_dir.as_path())
+			.into_vec_sorted_by(|p| Reverse(std::fs::metadata(p).map_or(0, |m| m.len())));
+		let sizes: Vec<u64> = found.iter()
+			.map(|p| std::fs::metadata(p).map_or(0, |m| m.len()))
+			.collect();
+		assert_eq!(found.len(), 3);
+		assert!(sizes.windows(2).all(|w| w[1] <= w[0]), "Not sorted: {sizes:?}");
+
+		// Ties should fall back to path order.
+		assert_eq!(
+			Dowser::from("tests/").into_vec_sorted_by(|_| 0),
+			Dowser::from("tests/").into_sorted_vec(),
+		);
+	}
+
+	#[test]
+	fn t_crawl_into() {
+		use std::collections::BTreeSet;
+
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		let set: BTreeSet<PathBuf> = Dowser::from(test_dir.as_path()).crawl_into();
+		let sorted = Dowser::from(test_dir.as_path()).into_sorted_vec();
+		assert_eq!(set.len(), 9);
+		assert!(set.into_iter().eq(sorted), "Crawl results differ.");
+	}
+
+	#[test]
+	fn t_with_forward_slashes() {
+		let found: Vec<String> = Dowser::from("tests/").with_forward_slashes().collect();
+		assert_eq!(found.len(), 9);
+		assert!(found.iter().all(|p| ! p.contains('\\')), "Backslashes found.");
+
+		#[cfg(unix)]
+		{
+			let mut found = found;
+			found.sort();
+			let expected: Vec<String> = Dowser::from("tests/")
+				.into_sorted_vec()
+				.into_iter()
+				.map(|p| p.to_string_lossy().into_owned())
+				.collect();
+			assert_eq!(found, expected);
+		}
+	}
+
+	#[test]
+	fn t_with_parsed_extensions() {
+		let test_dir = std::fs::canonicalize("./tests/assets")
+			.expect("Missing dowser test directory.");
+
+		let mut found: Vec<(PathBuf, Option<Extension>)> = Dowser::from(test_dir.as_path())
+			.with_parsed_extensions()
+			.collect();
+		found.sort_by(|a, b| a.0.cmp(&b.0));
+		assert_eq!(found, [
+			(test_dir.join("file.txt"), Some(Extension::new3(*b"txt"))),
+			(test_dir.join("functioning.JPEG"), Some(Extension::new4(*b"jpeg"))),
+			(test_dir.join("is-executable.sh"), Some(Extension::new2(*b"sh"))),
+		]);
+
+		// Extensionless.
+		let test_dir = std::fs::canonicalize("./tests/links/06")
+			.expect("Missing dowser test directory.");
+		assert!(
+			Dowser::from(test_dir.as_path())
+				.with_parsed_extensions()
+				.all(|(_, e)| e.is_none())
+		);
+	}
+
+	#[test]
+	fn t_into_vec_with_capacity() {
+		let found = Dowser::from("tests/").into_vec_with_capacity(100);
+		assert!(100 <= found.capacity());
+		assert_eq!(found.len(), 9);
+
+		let mut found = found;
+		found.sort();
+		assert_eq!(found, Dowser::from("tests/").into_sorted_vec());
+
+		// Too small is fine too.
+		assert_eq!(Dowser::from("tests/").into_vec_with_capacity(0).len(), 9);
+	}
+
+	#[test]
+	fn t_into_boxed_slice() {
+		let mut all: Box<[PathBuf]> = Dowser::from("tests/").into_boxed_slice();
+		all.sort();
+		assert_eq!(all.into_vec(), Dowser::from("tests/").into_sorted_vec());
+	}
+
+	#[test]
+	fn t_into_arc_vec() {
+		let mut all: Vec<Arc<Path>> = Dowser::from("tests/").into_arc_vec();
+		all.sort();
+		assert_eq!(
+			all.iter().map(|p| p.to_path_buf()).collect::<Vec<_>>(),
+			Dowser::from("tests/").into_sorted_vec(),
+		);
+	}
+
+	#[test]
+	fn t_into_os_strings() {
+		let mut all: Vec<OsString> = Dowser::from("tests/").into_os_strings();
+		all.sort();
+		assert_eq!(
+			all.into_iter().map(PathBuf::from).collect::<Vec<_>>(),
+			Dowser::from("tests/").into_sorted_vec(),
+		);
+	}
+
+	#[test]
+	fn t_split() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		let (mut dirs, mut files) = Dowser::from(test_dir.as_path()).split();
+		dirs.sort();
+		files.sort();
+		assert_eq!(dirs, [test_dir.clone(), test_dir.join("05"), test_dir.join("06")]);
+		assert_eq!(files, Dowser::from(test_dir.as_path()).into_sorted_vec());
+	}
+
+	#[test]
+	fn t_into_vec_resume() {
+		let all = Dowser::from("tests/").into_sorted_vec();
+
+		// Take a few, then finish up.
+		let mut crawl = Dowser::from("tests/");
+		let mut found: Vec<PathBuf> = crawl.by_ref().take(4).collect();
+		assert_eq!(found.len(), 4);
+		found.extend(crawl.into_vec());
+		found.sort();
+		assert_eq!(all, found);
+	}
+
+	#[test]
+	fn t_total_size() {
+		let all = Dowser::from("tests/").into_vec();
+		let bytes = all.iter()
+			.filter_map(|p| std::fs::metadata(p).ok())
+			.map(|m| m.len())
+			.sum::<u64>();
+
+		assert_eq!(Dowser::from("tests/").total_size(), (9, bytes));
+		assert_eq!(Dowser::default().total_size(), (0, 0));
+	}
+
+	#[test]
+	fn t_size_histogram() {
+		for (len, idx) in [
+			(0, 0),
+			(1023, 0),
+			(1024, 1),
+			(4095, 1),
+			(4096, 2),
+			((1 << 20) - 1, 5),
+			(1 << 20, 6),
+			((1 << 32) - 1, 11),
+			(1 << 32, 12),
+			(u64::MAX, 12),
+		] {
+			assert_eq!(size_bucket(len), idx, "Wrong bucket for {len}.");
+		}
+
+		let mut expected = [0_u64; Dowser::SIZE_BUCKETS];
+		for p in Dowser::from("tests/") {
+			let len = std::fs::metadata(p).expect("Missing metadata.").len();
+			expected[size_bucket(len)] += 1;
+		}
+
+		let hist = Dowser::from("tests/").size_histogram();
+		assert_eq!(hist, expected);
+		assert_eq!(hist.iter().sum::<u64>(), 9);
+		assert_eq!(Dowser::default().size_histogram(), [0; Dowser::SIZE_BUCKETS]);
+	}
+
+	#[test]
+	fn t_is_empty_result() {
+		assert!(Dowser::default().is_empty_result());
+		assert!(! Dowser::from("tests/").is_empty_result());
+		assert!(! Dowser::from("tests/assets/file.txt").is_empty_result());
+		assert!(Dowser::default().with_path("tests/nope").is_empty_result());
+		assert!(
+			Dowser::default()
+				.without_path("tests/links")
+				.with_path("tests/links")
+				.is_empty_result()
+		);
+	}
+
+	#[test]
+	fn t_into_entries_filtered() {
+		let mut expected: Vec<(PathBuf, u64)> = Dowser::from("tests/")
+			.into_vec()
+			.into_iter()
+			.filter_map(|p| {
+				let len = std::fs::metadata(&p).ok()?.len();
+				if 0 < len { Some((p, len)) }
+				else { None }
+			})
+			.collect();
+		expected.sort();
+		assert!(! expected.is_empty());
+
+		let mut found: Vec<(PathBuf, u64)> = Dowser::from("tests/")
+			.into_entries_filtered(|_, m| 0 < m.len())
+			.into_iter()
+			.map(|(p, m)| (p, m.len()))
+			.collect();
+		found.sort();
+		assert_eq!(found, expected);
+
+		// Everything should come through if we want everything.
+		assert_eq!(
+			Dowser::from("tests/").into_entries_filtered(|_, _| true).len(),
+			9,
+		);
+	}
+
+	#[test]
+	fn t_difference() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Links from one side of the tree to the other should be handled.
+		let mut diff = Dowser::from(test_dir.join("links/06"))
+			.difference(Dowser::from(test_dir.join("links/05")));
+		diff.sort();
+		assert_eq!(diff, [test_dir.join("links/01"), test_dir.join("links/06/09")]);
+
+		let both = Dowser::from(test_dir.join("links/06"))
+			.intersection(Dowser::from(test_dir.join("links/05")));
+		assert_eq!(both, [test_dir.join("links/06/08")]);
+
+		// Nothing in common.
+		let assets = Dowser::from(test_dir.join("assets")).into_sorted_vec();
+		let mut diff = Dowser::from(test_dir.join("assets"))
+			.difference(Dowser::from(test_dir.join("links")));
+		diff.sort();
+		assert_eq!(diff, assets);
+		assert!(
+			Dowser::from(test_dir.join("assets"))
+				.intersection(Dowser::from(test_dir.join("links")))
+				.is_empty()
+		);
+
+		// Everything in common.
+		assert!(
+			Dowser::from(test_dir.join("links"))
+				.difference(Dowser::from(test_dir.as_path()))
+				.is_empty()
+		);
+	}
+
+	#[test]
+	fn t_dedup_by_relative_name() {
+		use std::fs;
+
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		// Set up two layers.
+		let tmp = tmp.join("dowser-overlay");
+		let upper = tmp.join("upper");
+		let lower = tmp.join("lower");
+		let res = fs::create_dir_all(upper.join("sub"))
+			.and_then(|()| fs::create_dir_all(lower.join("sub")))
+			.and_then(|()| fs::write(upper.join("a.txt"), b"upper"))
+			.and_then(|()| fs::write(upper.join("c.txt"), b"upper"))
+			.and_then(|()| fs::write(lower.join("a.txt"), b"lower"))
+			.and_then(|()| fs::write(lower.join("sub/b.txt"), b"lower"))
+			.and_then(|()| fs::write(lower.join("c.txt"), b"lower"));
+
+		if res.is_ok() {
+			let upper = fs::canonicalize(&upper).expect("Missing upper.");
+			let lower = fs::canonicalize(&lower).expect("Missing lower.");
+
+			let mut found = Dowser::default()
+				.with_paths([&upper, &lower])
+				.dedup_by_relative_name();
+			found.sort();
+			assert_eq!(found, [
+				lower.join("sub/b.txt"),
+				upper.join("a.txt"),
+				upper.join("c.txt"),
+			]);
+
+			// Flip the order.
+			let mut found = Dowser::default()
+				.with_paths([&lower, &upper])
+				.dedup_by_relative_name();
+			found.sort();
+			assert_eq!(found, [
+				lower.join("a.txt"),
+				lower.join("c.txt"),
+				lower.join("sub/b.txt"),
+			]);
+
+			// File roots are keyed by name.
+			let found = Dowser::default()
+				.with_paths([upper.join("a.txt"), lower.join("a.txt")])
+				.dedup_by_relative_name();
+			assert_eq!(found, [upper.join("a.txt")]);
+		}
+
+		let _res = fs::remove_dir_all(tmp);
+	}
+
+	#[test]
+	fn t_cross_root_duplicates() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// One root, no dupes.
+		assert!(Dowser::from(test_dir.as_path()).cross_root_duplicates().is_empty());
+
+		// 05/08 links to 06/08.
+		let found = Dowser::default()
+			.with_paths([test_dir.join("05"), test_dir.join("06")])
+			.cross_root_duplicates();
+		assert_eq!(found, [test_dir.join("06/08")]);
+
+		// Same with a shared seen set.
+		let found = Dowser::default()
+			.with_shared_seen(SharedSeen::default())
+			.with_paths([test_dir.join("05"), test_dir.join("06")])
+			.cross_root_duplicates();
+		assert_eq!(found, [test_dir.join("06/08")]);
+
+		// The parent can reach everything in the child.
+		let mut found = Dowser::default()
+			.with_paths([test_dir.as_path(), &test_dir.join("06"), &test_dir.join("02")])
+			.cross_root_duplicates();
+		found.sort();
+		assert_eq!(found, [
+			test_dir.join("01"),
+			test_dir.join("02"),
+			test_dir.join("06/08"),
+			test_dir.join("06/09"),
+		]);
+
+		// Exclusions should still apply.
+		let found = Dowser::default()
+			.without_path(test_dir.join("06/08"))
+			.with_paths([test_dir.join("05"), test_dir.join("06")])
+			.cross_root_duplicates();
+		assert!(found.is_empty());
+	}
+
+	#[test]
+	fn t_deepest() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// The deepest files are in links/06, though 05/08 leads to 06/08 too.
+		let (depth, path) = Dowser::from(test_dir.as_path()).deepest()
+			.expect("Expected a deepest file.");
+		assert_eq!(depth, 3);
+		assert!(
+			path == test_dir.join("links/06/08") || path == test_dir.join("links/06/09"),
+			"Unexpected deepest file: {path:?}",
+		);
+
+		// File roots are zero.
+		assert_eq!(
+			Dowser::from(test_dir.join("assets/file.txt")).deepest(),
+			Some((0, test_dir.join("assets/file.txt"))),
+		);
+
+		// Nothing is nothing.
+		assert!(Dowser::default().deepest().is_none());
+
+		// Files pulled in from list files are roots, no matter how deep the
+		// list was.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+		let dir = tmp.join("dowser.test.deepest");
+		let _res = std::fs::remove_dir_all(&dir);
+		let res = std::fs::create_dir_all(dir.join("a/b"))
+			.and_then(|()| std::fs::write(dir.join("top.txt"), b""))
+			.and_then(|()| std::fs::write(
+				dir.join("a/b/deep.list"),
+				test_dir.join("assets/file.txt").to_string_lossy().as_bytes(),
+			));
+		if res.is_ok() {
+			let dir = std::fs::canonicalize(&dir).expect("Missing test dir.");
+			assert_eq!(
+				Dowser::default()
+					.with_list_files(Extension::new4(*b"list"))
+					.with_path(&dir)
+					.deepest(),
+				Some((1, dir.join("top.txt"))),
+			);
+
+			// Directory roots from list files start over at zero, even when
+			// they've been deferred.
+			let links = test_dir.join("links");
+			let res = std::fs::write(
+				dir.join("a/b/deep.list"),
+				links.to_string_lossy().as_bytes(),
+			);
+			if res.is_ok() {
+				for defer in [false, true] {
+					let mut crawl = Dowser::default()
+						.with_list_files(Extension::new4(*b"list"));
+					if defer { crawl = crawl.defer_roots(); }
+					let (depth, path) = crawl.with_path(&dir).deepest()
+						.expect("Expected a deepest file.");
+					assert_eq!(depth, 2);
+					assert!(
+						path == links.join("06/08") || path == links.join("06/09"),
+						"Unexpected deepest file: {path:?}",
+					);
+				}
+			}
+		}
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_only_empty() {
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		// Set up a little tree with a mix of empty and non-empty things.
+		let base = tmp.join("dowser.test.empty");
+		let _res = std::fs::remove_dir_all(&base);
+		let res = std::fs::create_dir_all(base.join("full/empty"))
+			.and_then(|()| std::fs::create_dir_all(base.join("empty")))
+			.and_then(|()| std::fs::write(base.join("full/empty.txt"), b""))
+			.and_then(|()| std::fs::write(base.join("full/full.txt"), b"Hello"));
+
+		if res.is_ok() {
+			let base = std::fs::canonicalize(&base).expect("Missing test dir.");
+
+			let found = Dowser::from(base.as_path()).only_empty_files();
+			assert_eq!(found, [base.join("full/empty.txt")]);
+
+			let mut found = Dowser::from(base.as_path()).only_empty_dirs();
+			found.sort();
+			assert_eq!(found, [base.join("empty"), base.join("full/empty")]);
+		}
+
+		let _res = std::fs::remove_dir_all(base);
+	}
+
+	#[test]
+	fn t_partition() {
+		let test_dir = std::fs::canonicalize("./tests/assets")
+			.expect("Missing dowser assets directory.");
+
+		let (mut yes, no) = Dowser::from(test_dir.as_path())
+			.partition(|p| p.extension().is_some_and(|e| e != "JPEG"));
+		yes.sort();
+		assert_eq!(yes, [test_dir.join("file.txt"), test_dir.join("is-executable.sh")]);
+		assert_eq!(no, [test_dir.join("functioning.JPEG")]);
+
+		// Root files should be partitioned too.
+		let (yes, no) = Dowser::from(test_dir.join("file.txt")).partition(|_| false);
+		assert!(yes.is_empty());
+		assert_eq!(no, [test_dir.join("file.txt")]);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_nlink_hint() {
+		// The hint shouldn't affect the results.
+		for path in ["tests/", "tests/links", "tests/assets/file.txt"] {
+			assert_eq!(
+				Dowser::default().with_nlink_hint().with_path(path).into_sorted_vec(),
+				Dowser::from(path).into_sorted_vec(),
+			);
+		}
+
+		// But it should reserve space, at least on filesystems that count
+		// subdirectories. (Links has two: 05 and 06.)
+		let mut crawl = Dowser::with_capacity(0);
+		crawl.dirs = VecDeque::new();
+		crawl.reserve_nlink(Path::new("tests/links"));
+		let nlink = std::fs::metadata("tests/links").map_or(0, |m| {
+			use std::os::unix::fs::MetadataExt;
+			m.nlink()
+		});
+		if nlink == 4 {
+			assert!(2 <= crawl.dirs.capacity());
+			assert!(2 <= crawl.seen.capacity());
+		}
+	}
+
+	#[test]
+	fn t_with_order() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser tests directory.");
+		let depth = |p: &PathBuf| p.strip_prefix(&test_dir).map_or(0, |p| p.components().count());
+
+		// Breadth-first results should never get shallower.
+		let found: Vec<PathBuf> = Dowser::default()
+			.with_order(Order::BreadthFirst)
+			.with_path(&test_dir)
+			.collect();
+		assert!(
+			found.windows(2).all(|w| depth(&w[0]) <= depth(&w[1])),
+			"Breadth-first results are out of order: {found:?}",
+		);
+
+		// Otherwise the results should be the same.
+		let mut found = found;
+		found.sort();
+		assert_eq!(found, Dowser::from(test_dir.as_path()).into_sorted_vec());
+		assert_eq!(
+			found,
+			Dowser::default()
+				.with_order(Order::DepthFirst)
+				.with_path(&test_dir)
+				.into_sorted_vec(),
+		);
+	}
+
+	#[test]
+	fn t_with_all_origins() {
+		let links = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser test directory.");
+
+		for order in [Order::DepthFirst, Order::BreadthFirst] {
+			let mut found = Dowser::default()
+				.with_order(order)
+				.with_paths([
+					links.join("06"),
+					links.join("nope"), // Invalid.
+					links.clone(),
+					links.join("06"),   // Duplicate.
+					links.join("01"),
+				])
+				.with_all_origins();
+			found.sort();
+			for (_, origins) in &mut found { origins.sort(); }
+
+			let (r01, r06) = (links.join("01"), links.join("06"));
+			assert_eq!(found, [
+				(links.join("01"), vec![links.clone(), r01, r06.clone()]),
+				(links.join("02"), vec![links.clone()]),
+				(links.join("03"), vec![links.clone()]),
+				(links.join("04"), vec![links.clone()]),
+				(links.join("06/08"), vec![links.clone(), r06.clone()]),
+				(links.join("06/09"), vec![links.clone(), r06]),
+			]);
+		}
+	}
+
+	#[test]
+	fn t_type_changed() {
+		let file = std::fs::canonicalize("tests/assets/file.txt")
+			.expect("Missing dowser test file.");
+
+		// Pretend the file was a directory when it was queued.
+		let mut crawl = Dowser::default();
+		crawl.dirs.push_back((file.clone(), 0));
+		assert_eq!(crawl.by_ref().collect::<Vec<_>>(), std::slice::from_ref(&file));
+		assert_eq!(crawl.type_changed(), std::slice::from_ref(&file));
+
+		// Filters still apply.
+		let mut crawl = Dowser::default().and_filter(|_| false);
+		crawl.dirs.push_back((file.clone(), 0));
+		assert!(crawl.next().is_none());
+		assert_eq!(crawl.type_changed(), [file]);
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn t_par_iter() {
+		use rayon::iter::ParallelIterator;
+
+		/// # Assets Only.
+		fn assets(p: &Path) -> bool { p.parent().is_some_and(|d| d.ends_with("assets")) }
+
+		let mut expected = Dowser::default().and_filter(assets).with_path("tests/").into_vec();
+		expected.sort();
+		assert_eq!(expected.len(), 3);
+
+		let mut found: Vec<PathBuf> = Dowser::default()
+			.and_filter(assets)
+			.with_path("tests/")
+			.par_iter()
+			.collect();
+		found.sort();
+		assert_eq!(expected, found);
+
+		// Again, in a specific pool.
+		let pool = rayon::ThreadPoolBuilder::new()
+			.num_threads(2)
+			.build()
+			.expect("Failed to build pool.");
+		let pool = Arc::new(pool);
+		let mut found: Vec<PathBuf> = Dowser::default()
+			.with_thread_pool(Arc::clone(&pool))
+			.and_filter(assets)
+			.with_path("tests/")
+			.par_iter()
+			.inspect(|_| assert!(pool.current_thread_index().is_some(), "Wrong pool!"))
+			.collect();
+		found.sort();
+		assert_eq!(expected, found);
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn t_par_without_paths() {
+		let excludes = ["tests/links/06", "tests/assets/file.txt", "tests/nope"];
+		let expected = Dowser::default()
+			.without_paths(excludes)
+			.with_path("tests/")
+			.into_sorted_vec();
+		assert!(! expected.is_empty() && expected.len() < 9, "Nothing was excluded.");
+
+		assert_eq!(
+			Dowser::default()
+				.par_without_paths(excludes.to_vec())
+				.with_path("tests/")
+				.into_sorted_vec(),
+			expected,
+		);
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn t_into_par_iter() {
+		use rayon::iter::{
+			IntoParallelIterator,
+			ParallelIterator,
+		};
+
+		// The links directory is full of overlap, so makes a good test of
+		// the deduplication.
+		for order in [Order::DepthFirst, Order::BreadthFirst] {
+			for roots in [&["tests/"][..], &["tests/links", "tests/links/06", "tests/assets"]] {
+				let expected = Dowser::default()
+					.with_order(order)
+					.with_paths(roots)
+					.into_sorted_vec();
+				assert!(! expected.is_empty());
+
+				let mut found: Vec<PathBuf> = Dowser::default()
+					.with_order(order)
+					.with_paths(roots)
+					.into_par_iter()
+					.collect();
+				found.sort();
+				assert_eq!(expected, found);
+			}
+		}
+
+		// Shared sets are respected.
+		let seen = SharedSeen::default();
+		let first: Vec<PathBuf> = Dowser::default()
+			.with_shared_seen(seen.clone())
+			.with_path("tests/links")
+			.into_par_iter()
+			.collect();
+		assert_eq!(first.len(), 6);
+		let second: Vec<PathBuf> = Dowser::default()
+			.with_shared_seen(seen)
+			.with_path("tests/")
+			.into_par_iter()
+			.collect();
+		assert_eq!(second.len(), 3);
+
+		// So are exclusions, though workers don't copy the seen set.
+		let mut found: Vec<PathBuf> = Dowser::default()
+			.without_paths(["tests/links/06", "tests/assets/file.txt"])
+			.with_path("tests/")
+			.into_par_iter()
+			.collect();
+		found.sort();
+		assert_eq!(found, Dowser::default()
+			.without_paths(["tests/links/06", "tests/assets/file.txt"])
+			.with_path("tests/")
+			.into_sorted_vec());
+		let file = std::fs::canonicalize("tests/assets/file.txt")
+			.expect("Missing dowser test file.");
+		assert!(! found.contains(&file));
+	}
+
+	#[cfg(feature = "glob")]
+	#[test]
+	fn t_with_glob_roots() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser tests directory.");
+
+		// Just the top-level links.
+		let found = Dowser::default()
+			.with_glob_roots("tests/links/0[1-4]")
+			.expect("Invalid glob.")
+			.into_sorted_vec();
+		assert_eq!(found, [
+			test_dir.join("links/01"),
+			test_dir.join("links/02"),
+			test_dir.join("links/03"),
+			test_dir.join("links/04"),
+		]);
+
+		// Directories are crawled, and overlaps deduped.
+		let found = Dowser::default()
+			.with_base_dir(test_dir.clone())
+			.with_glob_roots("*")
+			.expect("Invalid glob.")
+			.with_glob_roots("links/0*")
+			.expect("Invalid glob.")
+			.into_sorted_vec();
+		assert_eq!(found, Dowser::from(test_dir.as_path()).into_sorted_vec());
+
+		// No matches, no roots.
+		assert!(
+			Dowser::default()
+				.with_glob_roots("tests/nope*")
+				.expect("Invalid glob.")
+				.next()
+				.is_none()
+		);
+
+		// Bad patterns.
+		assert!(Dowser::default().with_glob_roots("tests/[").is_err());
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_without_symlink_path() {
+		let links = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser test directory.");
+
+		// 06/10 points to 01, which is otherwise only reachable from the top.
+		let found = Dowser::from("tests/links/06").into_sorted_vec();
+		assert_eq!(found, [links.join("01"), links.join("06/08"), links.join("06/09")]);
+
+		let found = Dowser::default()
+			.without_symlink_path("tests/links/06/10")
+			.with_path("tests/links/06")
+			.into_sorted_vec();
+		assert_eq!(found, [links.join("06/08"), links.join("06/09")]);
+
+		// The target is still reachable directly, unlike with without_path.
+		let found = Dowser::default()
+			.without_symlink_path("tests/links/06/10")
+			.with_path("tests/links")
+			.into_sorted_vec();
+		assert_eq!(found, Dowser::from("tests/links").into_sorted_vec());
+		assert!(found.contains(&links.join("01")));
+		assert!(
+			! Dowser::default()
+				.without_path("tests/links/06/10")
+				.with_path("tests/links")
+				.into_vec()
+				.contains(&links.join("01"))
+		);
+
+		// Directory links too. (07 points to 06.)
+		let found = Dowser::default()
+			.without_symlink_path(links.join("07"))
+			.with_path("tests/links")
+			.into_sorted_vec();
+		assert_eq!(found.len(), 6);
+
+		// Non-links are ignored.
+		let found = Dowser::default()
+			.without_symlink_path("tests/links/01")
+			.with_path("tests/links")
+			.into_sorted_vec();
+		assert_eq!(found.len(), 6);
+	}
+
+	#[test]
+	fn t_with_shared_seen() {
+		let seen = SharedSeen::default();
+
+		// The first crawl gets everything.
+		let links = Dowser::default()
+			.with_shared_seen(seen.clone())
+			.with_path("tests/links")
+			.into_vec();
+		assert_eq!(links.len(), 6);
+		assert!(! seen.is_empty());
+
+		// The second only gets what's left.
+		let rest = Dowser::default()
+			.with_shared_seen(seen.clone())
+			.with_path("tests/")
+			.into_sorted_vec();
+		assert_eq!(rest.len(), 3);
+		assert!(rest.iter().all(|p| ! links.contains(p)));
+
+		// And a third gets nothing at all.
+		assert!(
+			Dowser::default()
+				.with_shared_seen(seen.clone())
+				.with_path("tests/")
+				.next()
+				.is_none()
+		);
+
+		// Until it is cleared.
+		seen.clear();
+		assert_eq!(
+			Dowser::default().with_shared_seen(seen).with_path("tests/").count(),
+			9,
+		);
+	}
+
+	#[cfg(feature = "regex")]
+	#[test]
+	fn t_regex_exclude() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser tests directory.");
+
+		// Prune the links directory and JPEGs.
+		let mut found: Vec<PathBuf> = Dowser::default()
+			.regex_exclude(&["/links$", r"(?i)\.jpe?g$"])
+			.expect("Invalid regex.")
+			.with_path(&test_dir)
+			.collect();
+		found.sort();
+		assert_eq!(found, [
+			test_dir.join("assets/file.txt"),
+			test_dir.join("assets/is-executable.sh"),
+		]);
+
+		// Roots are subject to exclusion too.
+		assert!(
+			Dowser::default()
+				.regex_exclude(&["/assets$"])
+				.expect("Invalid regex.")
+				.with_path(test_dir.join("assets"))
+				.next()
+				.is_none()
+		);
+
+		// No patterns, no exclusions.
+		assert_eq!(
+			Dowser::default()
+				.regex_exclude(&[])
+				.expect("Invalid regex.")
+				.with_path(&test_dir)
+				.count(),
+			9,
+		);
+
+		// Bad patterns should fail.
+		assert!(Dowser::default().regex_exclude(&["(unclosed"]).is_err());
+	}
+
+	#[test]
+	fn t_without_symlinks() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// Directory five only contains a symlink.
+		assert_eq!(
+			Dowser::from(test_dir.join("05")).collect::<Vec<_>>(),
+			[test_dir.join("06/08")],
+		);
+		assert!(
+			Dowser::default()
+				.without_symlinks()
+				.with_path(test_dir.join("05"))
+				.next()
+				.is_none()
+		);
+
+		// But everything in the links directory is reachable without them.
+		let mut all: Vec<PathBuf> = Dowser::from(test_dir.as_path()).collect();
+		let mut found: Vec<PathBuf> = Dowser::default()
+			.with_follow_symlinks(false)
+			.with_path(&test_dir)
+			.collect();
+		all.sort();
+		found.sort();
+		assert_eq!(all, found);
+
+		// Roots are always resolved.
+		assert_eq!(
+			Dowser::default()
+				.without_symlinks()
+				.with_path(test_dir.join("06/10"))
+				.collect::<Vec<_>>(),
+			[test_dir.join("01")],
+		);
+	}
+
+	#[test]
+	fn t_with_ignore_files() {
+		use std::fs::File;
+		use std::io::Write;
+
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		// Mock up a small tree with a couple ignore files.
+		let dir = tmp.join("dowser.test.ignore");
+		let _res = std::fs::create_dir_all(dir.join("skip"));
+		let _res = std::fs::create_dir_all(dir.join("sub/skip"));
+		let res = [
+			(".ignore", "# Comment.\n*.log\n/skip/\n"),
+			("sub/.ignore", "e.txt"),
+			("a.txt", ""),
+			("a.log", ""),
+			("e.txt", ""),
+			("skip/b.txt", ""),
+			("sub/d.log", ""),
+			("sub/e.txt", ""),
+			("sub/skip/c.txt", ""),
+		].into_iter().try_for_each(|(name, text)|
+			File::create(dir.join(name))
+				.and_then(|mut file| file.write_all(text.as_bytes()).and_then(|()| file.flush()))
+		);
+
+		// Not all environments will allow that; only proceed with the testing
+		// if it worked.
+		if res.is_ok() {
+			let dir = std::fs::canonicalize(&dir).expect("Missing test dir.");
+
+			let found = Dowser::default()
+				.with_ignore_files(".ignore")
+				.with_path(&dir)
+				.into_sorted_vec();
+
+			let all = Dowser::from(dir.as_path()).into_vec();
+
+			// We don't need the files anymore.
+			let _res = std::fs::remove_dir_all(&dir);
+
+			assert_eq!(found, [
+				dir.join(".ignore"),
+				dir.join("a.txt"),
+				dir.join("e.txt"),
+				dir.join("sub/.ignore"),
+				dir.join("sub/skip/c.txt"),
+			]);
+			assert_eq!(all.len(), 9);
+		}
+	}
+
+	#[test]
+	fn t_with_bundle_dirs() {
+		use std::fs::File;
+
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		// Mock up a bundle.
+		let dir = tmp.join("dowser.test.bundle");
+		let _res = std::fs::create_dir_all(dir.join("Foo.APP"));
+		let res = File::create(dir.join("Foo.APP/a.txt"))
+			.and_then(|_| File::create(dir.join("b.txt")));
+
+		// Not all environments will allow that; only proceed with the testing
+		// if it worked.
+		if res.is_ok() {
+			let dir = std::fs::canonicalize(&dir).expect("Missing test dir.");
+
+			let found = Dowser::default()
+				.with_bundle_dirs(Extension::new3(*b"app"))
+				.with_path(&dir)
+				.into_sorted_vec();
+
+			// Roots are exempt.
+			let root = Dowser::default()
+				.with_bundle_dirs(Extension::new3(*b"app"))
+				.with_path(dir.join("Foo.APP"))
+				.into_vec();
+
+			// Filters still apply.
+			let filtered = Dowser::default()
+				.with_bundle_dirs(Extension::new3(*b"app"))
+				.and_filter(|p| Extension::new3(*b"txt") == p)
+				.with_path(&dir)
+				.into_vec();
+
+			// We don't need the files anymore.
+			let _res = std::fs::remove_dir_all(&dir);
+
+			assert_eq!(found, [dir.join("Foo.APP"), dir.join("b.txt")]);
+			assert_eq!(root, [dir.join("Foo.APP/a.txt")]);
+			assert_eq!(filtered, [dir.join("b.txt")]);
+		}
+	}
+
+	#[test]
+	fn t_without_dir_names() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Prune assets.
+		let found = Dowser::default()
+			.without_dir_names(&["assets"])
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found.len(), 6);
+		assert!(found.iter().all(|p| p.starts_with(test_dir.join("links"))));
+
+		// Roots are exempt.
+		let found = Dowser::default()
+			.without_dir_names(&["assets"])
+			.with_path(test_dir.join("assets"))
+			.into_vec();
+		assert_eq!(found.len(), 3);
+
+		// Links count too; "07" is a link to "06", which holds "11", a link
+		// back to "07".
+		let links = test_dir.join("links");
+		let found = Dowser::default()
+			.without_dir_names(&["07"])
+			.with_path(&links)
+			.into_sorted_vec();
+		assert_eq!(found, [
+			links.join("01"),
+			links.join("02"),
+			links.join("03"),
+			links.join("04"),
+			links.join("06/08"),
+			links.join("06/09"),
+		]);
+
+		// Matching is case-sensitive.
+		let found = Dowser::default()
+			.without_dir_names(&["ASSETS"])
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found.len(), 9);
+	}
+
+	#[test]
+	fn t_for_each_bounded() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		for cap in [0, 1, 100] {
+			let mut found = Vec::new();
+			Dowser::from(test_dir.as_path()).for_each_bounded(cap, |p| found.push(p));
+			found.sort();
+			assert_eq!(found, Dowser::from(test_dir.as_path()).into_sorted_vec());
+		}
+	}
+
+	#[test]
+	fn t_for_each_open() {
+		use std::io::Read;
+
+		let test_dir = std::fs::canonicalize("./tests/assets")
+			.expect("Missing dowser assets directory.");
+
+		let mut found = Vec::new();
+		Dowser::from(test_dir.as_path()).for_each_open(
+			|p, mut file| {
+				let mut raw = Vec::new();
+				file.read_to_end(&mut raw).expect("Failed to read file.");
+				found.push((p.to_path_buf(), raw.len()));
+			},
+			|p, e| panic!("Unable to open {p:?}: {e}"),
+		);
+
+		found.sort();
+		assert_eq!(found.len(), 3);
+		for (p, len) in found {
+			assert_eq!(
+				std::fs::metadata(&p).map(|m| m.len()).ok(),
+				u64::try_from(len).ok(),
+				"Length mismatch for {p:?}.",
+			);
+		}
+
+		// Errors should be reported. Files roots are resolved up front, so
+		// if we delete one before crawling, it won't be there to open.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+		let file = tmp.join("dowser.test.open");
+		if File::create(&file).is_err() { return; }
+		let crawl = Dowser::from(file.as_path());
+		let _res = std::fs::remove_file(&file);
+
+		let mut failed = Vec::new();
+		crawl.for_each_open(
+			|p, _| panic!("{p:?} should not have opened."),
+			|p, e| failed.push((p.to_path_buf(), e.kind())),
+		);
+		assert_eq!(failed.len(), 1);
+		assert!(failed[0].0.ends_with("dowser.test.open"));
+		assert_eq!(failed[0].1, ErrorKind::NotFound);
+	}
+
+	#[test]
+	fn t_with_resolved() {
+		let test_dir = std::fs::canonicalize("./tests/assets")
+			.expect("Missing dowser assets directory.");
+
+		let mut resolved = Dowser::default()
+			.with_resolved(test_dir.clone(), true)
+			.with_resolved(test_dir.join("file.txt"), false)
+			.into_vec();
+		resolved.sort();
+		assert_eq!(resolved, Dowser::from(test_dir.as_path()).into_sorted_vec());
+	}
+
+	#[test]
+	fn t_index() {
+		let mut paths = Dowser::from("./tests").into_sorted_vec();
+		paths.push(PathBuf::from("/weird/new\nline"));
+		paths.push(PathBuf::new());
+
+		let mut raw = Vec::new();
+		Dowser::write_index(&paths, &mut raw).expect("Failed to write index.");
+		assert_eq!(
+			Dowser::read_index(raw.as_slice()).expect("Failed to read index."),
+			paths,
+		);
+
+		// Nothing in, nothing out.
+		assert!(Dowser::read_index(std::io::empty()).expect("Failed to read index.").is_empty());
+
+		// Truncation should be an error, whether in the length or the body.
+		for len in [raw.len() - 1, raw.len() - 6, 2] {
+			let err = Dowser::read_index(&raw[..len]).expect_err("Truncation should fail.");
+			assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+		}
+	}
+
+	#[test]
+	fn t_with_base_dir() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Relative paths should be joined to the base.
+		let found = Dowser::default()
+			.with_base_dir(PathBuf::from("./tests"))
+			.without_path("links/06")
+			.with_path("links")
+			.into_sorted_vec();
+		assert_eq!(found, [
+			test_dir.join("links/01"),
+			test_dir.join("links/02"),
+			test_dir.join("links/03"),
+			test_dir.join("links/04"),
+			test_dir.join("links/06/08"), // Via 05/08.
+		]);
+
+		// Absolute paths should not.
+		let found = Dowser::default()
+			.with_base_dir(test_dir.join("links"))
+			.with_path(test_dir.join("assets/file.txt"))
+			.into_vec();
+		assert_eq!(found, [test_dir.join("assets/file.txt")]);
+
+		// Nor should paths added before the base.
+		let found = Dowser::default()
+			.with_path("tests/assets/file.txt")
+			.with_base_dir(test_dir.join("links"))
+			.with_path("tests/assets/file.txt")
+			.into_vec();
+		assert_eq!(found, [test_dir.join("assets/file.txt")]);
+	}
+
+	#[test]
+	fn t_normalize_roots() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Overlapping roots are exempt from pruning…
+		let found = Dowser::default()
+			.without_dir_names(&["links"])
+			.with_paths([test_dir.join("links"), test_dir.clone()])
+			.into_vec();
+		assert_eq!(found.len(), 9);
+
+		// …unless normalized.
+		let found = Dowser::default()
+			.without_dir_names(&["links"])
+			.with_paths([test_dir.join("links"), test_dir.clone()])
+			.normalize_roots()
+			.into_sorted_vec();
+		assert_eq!(found, [
+			test_dir.join("assets/file.txt"),
+			test_dir.join("assets/functioning.JPEG"),
+			test_dir.join("assets/is-executable.sh"),
+		]);
+
+		// Depth should count from the top.
+		let crawl = Dowser::default()
+			.with_paths([test_dir.join("links/06"), test_dir.join("links"), test_dir.join("assets")])
+			.normalize_roots();
+		let mut pending: Vec<&Path> = crawl.pending_dirs().collect();
+		pending.sort();
+		assert_eq!(pending, [test_dir.join("assets"), test_dir.join("links")]);
+		assert_eq!(crawl.deepest().map(|(d, _)| d), Some(2));
+
+		// The traversal order should be left alone.
+		for order in [Order::DepthFirst, Order::BreadthFirst] {
+			for roots in [["links", "links/06", "assets"], ["assets", "links/06", "links"]] {
+				let crawl = Dowser::default()
+					.with_order(order)
+					.with_paths(roots.map(|r| test_dir.join(r)))
+					.normalize_roots();
+				let expected = Dowser::default()
+					.with_order(order)
+					.with_paths(roots.into_iter().filter(|r| *r != "links/06").map(|r| test_dir.join(r)));
+				assert_eq!(
+					crawl.pending_dirs().collect::<Vec<_>>(),
+					expected.pending_dirs().collect::<Vec<_>>(),
+				);
+			}
+		}
+
+		// The results are otherwise the same.
+		assert_eq!(
+			Dowser::default()
+				.with_paths([test_dir.join("links/06"), test_dir.join("links")])
+				.normalize_roots()
+				.into_sorted_vec(),
+			Dowser::from(test_dir.join("links")).into_sorted_vec(),
+		);
+	}
+
+	#[test]
+	fn t_defer_roots() {
+		use std::sync::atomic::{
+			AtomicUsize,
+			Ordering::SeqCst,
+		};
+
+		static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// File roots are normally filtered right away.
+		let crawl = Dowser::default()
+			.and_filter(|_| { CALLS.fetch_add(1, SeqCst); true })
+			.with_path("tests/assets/file.txt");
+		assert_eq!(CALLS.load(SeqCst), 1);
+		drop(crawl);
+
+		// But not when deferring.
+		CALLS.store(0, SeqCst);
+		let crawl = Dowser::default()
+			.and_filter(|_| { CALLS.fetch_add(1, SeqCst); true })
+			.defer_roots()
+			.with_path("tests/assets/file.txt")
+			.with_path("tests/links");
+		assert_eq!(CALLS.load(SeqCst), 0);
+		assert_eq!(crawl.size_hint(), (0, None));
+		let found = crawl.into_sorted_vec();
+		assert_eq!(CALLS.load(SeqCst), 7);
+		assert_eq!(found.len(), 7);
+		assert!(found.contains(&test_dir.join("assets/file.txt")));
+
+		// Root settings can come after the roots.
+		let found = Dowser::default()
+			.defer_roots()
+			.with_paths(["tests/assets/file.txt", "tests/links"])
+			.require_file_roots()
+			.into_vec();
+		assert_eq!(found, [test_dir.join("assets/file.txt")]);
+
+		// Deferred roots are otherwise the same.
+		let found = Dowser::default()
+			.defer_roots()
+			.with_base_dir(test_dir.clone())
+			.with_path("links")
+			.into_sorted_vec();
+		assert_eq!(found, Dowser::from(test_dir.join("links")).into_sorted_vec());
+	}
+
+	#[test]
+	fn t_with_pathbuf() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+		let expected = Dowser::from(test_dir.join("links")).into_sorted_vec();
+
+		// Immediate and deferred, absolute and relative.
+		for defer in [false, true] {
+			let mut crawl = Dowser::default();
+			if defer { crawl = crawl.defer_roots(); }
+
+			let found = crawl.clone().with_pathbuf(test_dir.join("links")).into_sorted_vec();
+			assert_eq!(found, expected);
+
+			let found = crawl.with_base_dir(test_dir.clone())
+				.with_pathbuf(PathBuf::from("links"))
+				.into_sorted_vec();
+			assert_eq!(found, expected);
+		}
+	}
+
+	#[test]
+	fn t_require_roots() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// Directories only.
+		let found = Dowser::default()
+			.require_dir_roots()
+			.with_paths([test_dir.join("02"), test_dir.join("05")])
+			.into_vec();
+		assert_eq!(found, [test_dir.join("06/08")]);
+
+		// Files only.
+		let found = Dowser::default()
+			.require_file_roots()
+			.with_paths([test_dir.join("02"), test_dir.join("05")])
+			.into_vec();
+		assert_eq!(found, [test_dir.join("02")]);
+
+		// Errors.
+		let err = Dowser::default()
+			.require_dir_roots()
+			.try_with_path(test_dir.join("02"))
+			.expect_err("Files should be rejected.");
+		assert_eq!(err.kind(), ErrorKind::NotADirectory);
+
+		let err = Dowser::default()
+			.require_file_roots()
+			.try_with_path(test_dir.join("05"))
+			.expect_err("Directories should be rejected.");
+		assert_eq!(err.kind(), ErrorKind::IsADirectory);
+
+		let err = Dowser::default()
+			.try_with_path(test_dir.join("nope"))
+			.expect_err("Missing paths should be rejected.");
+		assert_eq!(err.kind(), ErrorKind::NotFound);
+
+		let err = Dowser::default()
+			.with_root_filter(|_| false)
+			.try_with_path(test_dir.join("02"))
+			.expect_err("Filtered paths should be rejected.");
+		assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+		// Successes.
+		let found = Dowser::default()
+			.require_file_roots()
+			.try_with_path(test_dir.join("02"))
+			.expect("Files should be accepted.")
+			.into_vec();
+		assert_eq!(found, [test_dir.join("02")]);
+	}
+
+	#[test]
+	fn t_with_root_filter() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// Only directory roots.
+		let mut found = Dowser::default()
+			.with_root_filter(Path::is_dir)
+			.with_paths([test_dir.join("02"), test_dir.join("05")])
+			.into_vec();
+		found.sort();
+		assert_eq!(found, [test_dir.join("06/08")]);
+
+		// Only file roots. Note this should _not_ affect the descendants.
+		let found = Dowser::default()
+			.with_root_filter(Path::is_file)
+			.with_paths([test_dir.join("02"), test_dir.join("05")])
+			.into_vec();
+		assert_eq!(found, [test_dir.join("02")]);
+
+		// Roots are canonicalized before filtering, so "07" is "06".
+		let found = Dowser::default()
+			.with_root_filter(|p| p.ends_with("06"))
+			.with_path(test_dir.join("07"))
+			.into_vec();
+		assert_eq!(found.len(), 3);
+	}
+
+	#[test]
+	fn t_with_dir_filter() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Skip reporting links, but still crawl it.
+		let (dirs, files) = Dowser::default()
+			.with_dir_filter(|p| ! p.ends_with("links"))
+			.with_path(&test_dir)
+			.split();
+		assert!(! dirs.contains(&test_dir.join("links")));
+		assert!(dirs.contains(&test_dir.join("links/06")));
+		assert_eq!(dirs.len(), 4);
+		assert_eq!(files.len(), 9);
+
+		// The empty directories should be filtered too.
+		assert!(
+			Dowser::default()
+				.with_dir_filter(|_| false)
+				.with_path(&test_dir)
+				.only_empty_dirs()
+				.is_empty()
+		);
+	}
+
+	#[test]
+	fn t_and_filter() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// One filter.
+		let sub = test_dir.join("06");
+		let found = Dowser::default()
+			.and_filter(move |p| p.starts_with(&sub))
+			.with_path(&test_dir)
+			.into_sorted_vec();
+		assert_eq!(found, [test_dir.join("06/08"), test_dir.join("06/09")]);
+
+		// Two filters; both must pass.
+		let sub = test_dir.join("06");
+		let found = Dowser::default()
+			.and_filter(move |p| p.starts_with(&sub))
+			.and_filter(|p| p.ends_with("09"))
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found, [test_dir.join("06/09")]);
+
+		// They should run in order, stopping at the first failure.
+		let found = Dowser::default()
+			.and_filter(|_| false)
+			.and_filter(|_| panic!("The second filter should not be called."))
+			.with_path(&test_dir)
+			.into_vec();
+		assert!(found.is_empty(), "Nothing should have passed.");
+
+		// Filtering shouldn't prevent descent.
+		let found = Dowser::default()
+			.and_filter(|p| p.ends_with("08"))
+			.with_path(test_dir.join("05"))
+			.into_vec();
+		assert_eq!(found, [test_dir.join("06/08")]);
+
+		// File roots added before the filter should still be subject to it.
+		let found = Dowser::from(test_dir.join("06/08"))
+			.and_filter(|_| false)
+			.into_vec();
+		assert!(found.is_empty(), "Nothing should have passed.");
+		let found = Dowser::default()
+			.with_paths([test_dir.join("06/08"), test_dir.join("06/09")])
+			.with_name_filter(|n| n == "09")
+			.into_vec();
+		assert_eq!(found, [test_dir.join("06/09")]);
+	}
+
+	#[cfg(target_os = "linux")]
+	#[test]
+	fn t_parse_virtual_mounts() {
+		let raw = [
+			"proc /proc proc rw,nosuid,nodev,noexec,relatime 0 0",
+			"sysfs /sys sysfs rw,nosuid,nodev,noexec,relatime 0 0",
+			"/dev/nvme0n1p2 / ext4 rw,relatime 0 0",
+			"tmpfs /tmp tmpfs rw,nosuid,nodev 0 0",
+			"devpts /dev/pts devpts rw,nosuid,noexec,relatime 0 0",
+			r"binfmt_misc /proc/sys/fs/binfmt\040misc binfmt_misc rw,relatime 0 0",
+			"garbage",
+		].join("\n");
+		assert_eq!(parse_virtual_mounts(&raw), [
+			PathBuf::from("/proc"),
+			PathBuf::from("/sys"),
+			PathBuf::from("/dev/pts"),
+			PathBuf::from("/proc/sys/fs/binfmt misc"),
+		]);
+	}
+
+	#[test]
+	fn t_with_lazy_paths() {
+		use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Count the pulls.
+		let pulled = Arc::new(AtomicUsize::new(0));
+		let pulled2 = Arc::clone(&pulled);
+		let mut crawl = Dowser::default()
+			.with_lazy_paths(
+				["./tests/links", "./tests/links/01", "./tests/nope"].into_iter()
+					.inspect(move |_| { pulled2.fetch_add(1, SeqCst); })
+			)
+			.with_lazy_paths(["./tests/assets"]);
+
+		// Nothing should be resolved yet.
+		assert_eq!(pulled.load(SeqCst), 0);
+		assert_eq!(crawl.size_hint(), (0, None));
+
+		// The first root is a directory, so that should be all it needs.
+		assert!(crawl.next().is_some());
+		assert_eq!(pulled.load(SeqCst), 1);
+
+		// The rest should come through in the end.
+		assert_eq!(crawl.count(), 8);
+		assert_eq!(pulled.load(SeqCst), 3);
+
+		// Same for the optimized consumers, including those that drain files
+		// before fetching more directories.
+		let regular = Dowser::from(test_dir.as_path()).into_sorted_vec();
+		let lazy = Dowser::default()
+			.with_lazy_paths(["./tests/assets/file.txt", "./tests/links", "./tests/assets"])
+			.into_sorted_vec();
+		assert_eq!(regular, lazy);
+
+		let (yes, no) = Dowser::default()
+			.with_lazy_paths(["./tests/assets/file.txt", "./tests/links/01"])
+			.partition(|p| p.ends_with("file.txt"));
+		assert_eq!(yes.len(), 1);
+		assert_eq!(no.len(), 1);
+
+		// Lazy roots should be deduplicated against regular ones.
+		let found = Dowser::default()
+			.with_lazy_paths(["./tests/links", "./tests/assets"])
+			.with_path(test_dir.join("links"))
+			.into_vec();
+		assert_eq!(found.len(), 9);
+	}
+
+	#[test]
+	fn t_with_list_files() {
+		const LIST: Extension = Extension::new4(*b"list");
+
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		let links = std::fs::canonicalize("tests/links")
+			.expect("Missing dowser links dir");
+		let asset = std::fs::canonicalize("tests/assets/file.txt")
+			.expect("Missing dowser assets/file.txt");
+
+		// Mock up a couple of lists referencing each other and the test
+		// assets.
+		let dir = tmp.join("dowser-list-files");
+		let list1 = dir.join("one.list");
+		let list2 = dir.join("two.LIST");
+		let _res = std::fs::remove_dir_all(&dir);
+		let res = std::fs::create_dir_all(&dir)
+			.and_then(|()| std::fs::write(&list1, format!(
+				"{}\n\n{}\n",
+				links.display(),
+				list2.display(),
+			)))
+			.and_then(|()| std::fs::write(&list2, format!(
+				"  {}  \n{}\n",
+				asset.display(),
+				list1.display(),
+			)))
+			.and_then(|()| std::fs::write(dir.join("other.txt"), b"Hello."));
+
+		// Not all environments will allow that; only proceed with the testing
+		// if it worked.
+		if res.is_ok() {
+			let dir = std::fs::canonicalize(&dir).expect("Missing temporary directory.");
+
+			// Without the setting, the lists are just files.
+			let found = Dowser::from(dir.as_path()).into_sorted_vec();
+			assert_eq!(found.len(), 3);
+
+			// With it, they should be expanded.
+			let mut found = Dowser::default()
+				.with_list_files(LIST)
+				.with_path(&dir)
+				.into_sorted_vec();
+			assert_eq!(found.len(), 8);
+			assert!(found.contains(&asset));
+			assert!(found.contains(&dir.join("other.txt")));
+			found.retain(|p| ! p.starts_with(&dir) && p != &asset);
+			assert_eq!(found, Dowser::from(links.as_path()).into_sorted_vec());
+
+			// List roots should work too.
+			let found = Dowser::default()
+				.with_list_files(LIST)
+				.with_path(&list2)
+				.into_vec();
+			assert_eq!(found.len(), 7);
+
+			let _res = std::fs::remove_dir_all(&dir);
+		}
+	}
+
+	#[test]
+	fn t_with_capacity() {
+		let crawl = Dowser::default();
+		assert!(Dowser::DEFAULT_SEEN_CAPACITY <= crawl.seen.capacity());
+		assert!(Dowser::DEFAULT_QUEUE_CAPACITY <= crawl.files.capacity());
+		assert!(Dowser::DEFAULT_QUEUE_CAPACITY <= crawl.dirs.capacity());
+
+		let crawl = Dowser::with_capacity(Dowser::DEFAULT_SEEN_CAPACITY * 4);
+		assert!(Dowser::DEFAULT_SEEN_CAPACITY * 4 <= crawl.seen.capacity());
+
+		// Results should be the same regardless.
+		assert_eq!(
+			Dowser::with_capacity(0).with_path("./tests").into_sorted_vec(),
+			Dowser::from("./tests").into_sorted_vec(),
+		);
+	}
+
+	#[test]
+	fn t_with_depth_ceiling() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Nothing should be too deep by default.
+		let mut crawl = Dowser::from(test_dir.as_path());
+		assert_eq!(crawl.by_ref().count(), 9);
+		assert!(crawl.too_deep().is_empty());
+
+		let mut crawl = Dowser::default()
+			.with_depth_ceiling(1)
+			.with_path(&test_dir);
+		assert_eq!(crawl.by_ref().count(), 7);
+		let mut too_deep = crawl.too_deep().to_vec();
+		too_deep.sort();
+		assert_eq!(too_deep, [test_dir.join("links/05"), test_dir.join("links/06")]);
+
+		// Roots are always read.
+		let found = Dowser::default()
+			.with_depth_ceiling(0)
+			.with_path(test_dir.join("assets"))
+			.into_vec();
+		assert_eq!(found.len(), 3);
+	}
+
+	#[test]
+	fn t_with_name_filter() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Directory names shouldn't matter.
+		let found = Dowser::default()
+			.with_name_filter(|n| n.as_encoded_bytes().starts_with(b"0"))
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found.len(), 6);
+
+		// Filters should compose.
+		let found = Dowser::default()
+			.with_name_filter(|n| n.as_encoded_bytes().starts_with(b"0"))
+			.with_name_filter(|n| n == "09")
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found, [test_dir.join("links/06/09")]);
+
+		let found = Dowser::default()
+			.with_name_filter(|n| 10 < n.len())
+			.and_filter(|p| p.starts_with(std::env::current_dir().unwrap_or_default()))
+			.with_path(&test_dir)
+			.into_sorted_vec();
+		assert_eq!(found, [
+			test_dir.join("assets/functioning.JPEG"),
+			test_dir.join("assets/is-executable.sh"),
+		]);
+	}
+
+	#[test]
+	fn t_with_extension_str() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		let found = Dowser::default()
+			.with_extension_str(".JPEG")
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found, [test_dir.join("assets/functioning.JPEG")]);
+
+		let found = Dowser::default()
+			.with_extensions_str("txt,sh,gif".split(','))
+			.with_path(&test_dir)
+			.into_sorted_vec();
+		assert_eq!(found, [
+			test_dir.join("assets/file.txt"),
+			test_dir.join("assets/is-executable.sh"),
+		]);
+
+		// Filters should compose.
+		let found = Dowser::default()
+			.with_extensions_str(["txt", "sh"])
+			.with_extension_str("sh")
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found, [test_dir.join("assets/is-executable.sh")]);
+
+		// File roots queued first should be filtered too.
+		let found = Dowser::from(test_dir.join("assets/file.txt"))
+			.with_extension_str("sh")
+			.into_vec();
+		assert!(found.is_empty(), "Nothing should have passed.");
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid extension.")]
+	fn t_with_extension_str_bad() {
+		let _res = Dowser::default().with_extension_str("j.pg");
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_with_max_symlink_hops() {
+		let links = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser test directory.");
+		let expected = Dowser::from(links.as_path()).into_sorted_vec();
+
+		// 06/11 -> 07 -> 06 is the only chain with two hops.
+		let mut crawl = Dowser::default()
+			.with_max_symlink_hops(1)
+			.with_broken_symlink_report()
+			.with_path(&links);
+		let mut found: Vec<PathBuf> = crawl.by_ref().collect();
+		found.sort();
+		assert_eq!(found, expected);
+		let rejected: Vec<&Path> = crawl.broken_symlinks().iter()
+			.map(|(p, _)| p.as_path())
+			.collect();
+		assert_eq!(rejected, [links.join("06/11")]);
+
+		// Zero rejects everything, but everything here is reachable anyway.
+		let mut crawl = Dowser::default()
+			.with_max_symlink_hops(0)
+			.with_broken_symlink_report()
+			.with_path(&links);
+		let mut found: Vec<PathBuf> = crawl.by_ref().collect();
+		found.sort();
+		assert_eq!(found, expected);
+		let mut rejected: Vec<PathBuf> = crawl.broken_symlinks().iter()
+			.map(|(p, _)| p.clone())
+			.collect();
+		rejected.sort();
+		assert_eq!(rejected, [
+			links.join("05/08"),
+			links.join("06/10"),
+			links.join("06/11"),
+			links.join("07"),
+		]);
+	}
+
+	#[test]
+	fn t_broken_symlinks() {
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		// Mock up a directory with one good and one broken link.
+		let dir = tmp.join("dowser-broken-links");
+		let _res = std::fs::remove_dir_all(&dir);
+		let res = std::fs::create_dir_all(&dir)
+			.and_then(|()| std::fs::write(dir.join("good"), b"Hello."))
+			.and_then(|()| std::os::unix::fs::symlink("good", dir.join("link")))
+			.and_then(|()| std::os::unix::fs::symlink("nope", dir.join("broken")));
+
+		// Not all environments will allow that; only proceed with the testing
+		// if it worked.
+		if res.is_ok() {
+			let dir = std::fs::canonicalize(&dir).expect("Missing temporary directory.");
+
+			// Nothing without asking.
+			let mut crawl = Dowser::from(dir.as_path());
+			assert_eq!(crawl.by_ref().count(), 1);
+			assert!(crawl.broken_symlinks().is_empty());
+
+			// Followed or not, the broken link should be reported.
+			for follow in [true, false] {
+				let mut crawl = Dowser::default()
+					.with_follow_symlinks(follow)
+					.with_broken_symlink_report()
+					.with_path(&dir);
+				assert_eq!(crawl.by_ref().count(), 1);
+
+				let report = crawl.broken_symlinks();
+				assert_eq!(report.len(), 1, "Expected one broken link.");
+				assert_eq!(report[0].0, dir.join("broken"));
+				assert_eq!(report[0].1.kind(), ErrorKind::NotFound);
+			}
+
+			let _res = std::fs::remove_dir_all(&dir);
+		}
+	}
+
+	#[test]
+	fn t_symlink_report() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		// Nothing without asking.
+		let mut crawl = Dowser::from(test_dir.as_path());
+		assert_eq!(crawl.by_ref().count(), 6);
+		assert!(crawl.symlink_report().is_empty());
+
+		let mut crawl = Dowser::default()
+			.with_symlink_report()
+			.with_path(&test_dir);
+		assert_eq!(crawl.by_ref().count(), 6);
+
+		let mut report = crawl.symlink_report().to_vec();
+		report.sort();
+		assert_eq!(report, [
+			(test_dir.join("05/08"), test_dir.join("06/08")),
+			(test_dir.join("06/10"), test_dir.join("01")),
+			(test_dir.join("06/11"), test_dir.join("06")),
+			(test_dir.join("07"), test_dir.join("06")),
+		]);
+
+		// The same links should be reported when not following them.
+		let mut crawl = Dowser::default()
+			.without_symlinks()
+			.with_symlink_report()
+			.with_path(&test_dir);
+		assert_eq!(crawl.by_ref().count(), 6);
+		let mut report2 = crawl.symlink_report().to_vec();
+		report2.sort();
+		assert_eq!(report, report2);
+	}
+
+	#[test]
+	fn t_symlinks_only() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
 
-		self.seen.extend(paths.into_iter().filter_map(|p|
-			std::fs::canonicalize(p).ok().map(|p| Entry::hash_path(&p))
-		));
-		self
+		let mut found = Dowser::from(test_dir.as_path()).symlinks_only();
+		found.sort();
+		assert_eq!(found, [
+			(test_dir.join("05/08"), test_dir.join("06/08")),
+			(test_dir.join("06/10"), test_dir.join("01")),
+			(test_dir.join("06/11"), test_dir.join("06")),
+			(test_dir.join("07"), test_dir.join("06")),
+		]);
+
+		// Nothing to see here.
+		assert!(Dowser::from("./tests/assets").symlinks_only().is_empty());
 	}
-}
 
-impl Dowser {
-	#[must_use]
-	/// # Consume Into Vec.
-	///
-	/// This method is an optimized alternative to running
-	/// `Dowser.iter().collect::<Vec<PathBuf>>()`.
-	///
-	/// It yields the same results as the above, but makes fewer allocations
-	/// along the way.
-	///
-	/// ## Examples
-	///
-	/// ```no_run
-	/// use dowser::Dowser;
-	/// use std::path::PathBuf;
-	///
-	/// // The iterator way.
-	/// let files: Vec<PathBuf> = Dowser::default()
-	///     .with_path("/usr/share")
-	///     .collect();
-	///
-	/// // The optimized way.
-	/// let files: Vec<PathBuf> = Dowser::default()
-	///     .with_path("/usr/share")
-	///     .into_vec();
-	/// ```
-	pub fn into_vec(self) -> Vec<PathBuf> {
-		let Self { mut files, mut dirs, mut seen } = self;
+	#[test]
+	fn t_hash_kind() {
+		/// # Mock Tree.
+		///
+		/// A root containing the same path as both a file and a directory.
+		struct Mock;
 
-		if ! dirs.is_empty() {
-			loop {
-				for p in std::mem::take(&mut dirs) {
-					if let Ok(rd) = std::fs::read_dir(p) {
-						for e in rd {
-							if let Some(e) = Entry::from_entry(e) {
-								if seen.insert(e.hash) {
-									if e.is_dir { dirs.push(e.path); }
-									else { files.push(e.path); }
-								}
-							}
-						}
-					}
+		impl DirSource for Mock {
+			fn read_dir(&self, path: &Path) -> std::io::Result<Vec<(PathBuf, bool)>> {
+				match path.to_str() {
+					Some("/r") => Ok(vec![
+						(PathBuf::from("/r/a"), false),
+						(PathBuf::from("/r/a"), true),
+					]),
+					Some("/r/a") => Ok(vec![(PathBuf::from("/r/a/b"), false)]),
+					_ => Err(std::io::Error::from(ErrorKind::NotFound)),
 				}
-
-				if dirs.is_empty() { break; }
 			}
 		}
 
-		// Done!
-		files
-	}
+		let path = Path::new("/r/a");
+		assert_ne!(
+			Entry::hash_path(path, false),
+			Entry::hash_path(path, true),
+			"File and directory hashes should differ.",
+		);
 
-	#[must_use]
-	/// # Consume Into Vec (Filtered).
-	///
-	/// This method is an optimized alternative to running
-	/// `Dowser.iter().filter(…).collect::<Vec<PathBuf>>()`.
-	///
-	/// It yields the same results as the above, but makes fewer allocations
-	/// along the way.
-	///
-	/// Note: every entry passed to your callback will be a valid, canonical
-	/// file path. (You don't have to explicitly test for [`is_file`](std::path::Path::is_file) or
-	/// anything like that.)
-	///
-	/// ## Examples
-	///
-	/// ```no_run
-	/// use dowser::Dowser;
-	/// use std::path::PathBuf;
-	///
-	/// // The iterator way.
-	/// let files: Vec<PathBuf> = Dowser::default()
-	///     .with_path("/usr/share")
-	///     .filter(|p|
-	///         p.extension().map_or(
-    ///             false,
-    ///             |e| e.eq_ignore_ascii_case("jpg")
-    ///         )
-	///     )
-	///     .collect();
-	///
-	/// // The optimized way.
-	/// let files: Vec<PathBuf> = Dowser::default()
-	///     .with_path("/usr/share")
-	///     .into_vec_filtered(|p|
-	///         p.extension().map_or(
-    ///             false,
-    ///             |e| e.eq_ignore_ascii_case("jpg")
-    ///         )
-	///     );
-	/// ```
-	pub fn into_vec_filtered<F>(self, cb: F) -> Vec<PathBuf>
-	where F: Fn(&Path) -> bool + Sync + Send {
-		let Self { mut files, mut dirs, mut seen } = self;
+		// Neither should suppress the other.
+		let found = Dowser::default()
+			.with_source(Mock)
+			.with_resolved(PathBuf::from("/r"), true)
+			.into_sorted_vec();
+		assert_eq!(found, [PathBuf::from("/r/a"), PathBuf::from("/r/a/b")]);
 
-		// We wouldn't have had a chance to filter these yet.
-		if ! files.is_empty() { files.retain(|p| cb(p)); }
+		// Nor should a file root suppress a directory of the same name.
+		let found = Dowser::default()
+			.with_source(Mock)
+			.with_resolved(PathBuf::from("/r/a"), false)
+			.with_resolved(PathBuf::from("/r/a"), true)
+			.into_sorted_vec();
+		assert_eq!(found, [PathBuf::from("/r/a"), PathBuf::from("/r/a/b")]);
+	}
 
-		if ! dirs.is_empty() {
-			loop {
-				for p in std::mem::take(&mut dirs) {
-					if let Ok(rd) = std::fs::read_dir(p) {
-						for e in rd {
-							if let Some(e) = Entry::from_entry(e) {
-								if seen.insert(e.hash) {
-									if e.is_dir { dirs.push(e.path); }
-									else if cb(&e.path) { files.push(e.path); }
-								}
-							}
-						}
-					}
-				}
+	#[test]
+	fn t_with_source() {
+		/// # Mock Tree.
+		///
+		/// A couple levels of directories, with a cycle, a dupe, and an
+		/// unreadable spot.
+		struct Mock;
 
-				if dirs.is_empty() { break; }
+		impl DirSource for Mock {
+			fn read_dir(&self, path: &Path) -> std::io::Result<Vec<(PathBuf, bool)>> {
+				let out = match path.to_str() {
+					Some("/r") => vec![
+						(PathBuf::from("/r/a"), true),
+						(PathBuf::from("/r/b"), true),
+						(PathBuf::from("/r/x"), true),
+						(PathBuf::from("/r/1"), false),
+					],
+					Some("/r/a") => vec![
+						(PathBuf::from("/r/a/2"), false),
+						(PathBuf::from("/r/b"), true), // Dupe.
+						(PathBuf::from("/r"), true), // Cycle.
+					],
+					Some("/r/b") => vec![
+						(PathBuf::from("/r/b/3"), false),
+						(PathBuf::from("/r/1"), false), // Dupe.
+					],
+					Some("/r/x") => return Err(std::io::Error::from(ErrorKind::PermissionDenied)),
+					_ => return Err(std::io::Error::from(ErrorKind::NotFound)),
+				};
+				Ok(out)
 			}
 		}
 
-		// Done!
-		files
+		let mut crawl = Dowser::default()
+			.on_permission_denied(PermissionPolicy::Count)
+			.with_source(Mock)
+			.with_resolved(PathBuf::from("/r"), true);
+		let mut found: Vec<PathBuf> = crawl.by_ref().collect();
+		found.sort();
+		assert_eq!(found, [
+			PathBuf::from("/r/1"),
+			PathBuf::from("/r/a/2"),
+			PathBuf::from("/r/b/3"),
+		]);
+		assert_eq!(crawl.permission_denied(), 1);
+
+		// Depth and pruning should work too.
+		let found = Dowser::default()
+			.with_min_depth(2)
+			.without_dir_names(&["b"])
+			.with_source(Mock)
+			.with_resolved(PathBuf::from("/r"), true)
+			.into_vec();
+		assert_eq!(found, [PathBuf::from("/r/a/2")]);
+
+		// As should limits. Only "a" is queued from the root, but "b" gets
+		// picked up from there.
+		let (dirs, files) = Dowser::default()
+			.with_per_level_dir_limit(1)
+			.with_source(Mock)
+			.with_resolved(PathBuf::from("/r"), true)
+			.split();
+		assert_eq!(dirs, [PathBuf::from("/r"), PathBuf::from("/r/a"), PathBuf::from("/r/b")]);
+		assert_eq!(files.len(), 3);
+	}
+
+	#[test]
+	fn t_with_prefetch() {
+		for dir in ["./tests", "./tests/links"] {
+			let found = Dowser::default()
+				.with_prefetch()
+				.with_path(dir)
+				.into_sorted_vec();
+			assert!(! found.is_empty(), "Nothing found in {dir}.");
+			assert_eq!(found, Dowser::from(dir).into_sorted_vec());
+		}
+	}
+
+	#[test]
+	fn t_with_retry() {
+		// Retrying shouldn't change anything when nothing goes wrong.
+		let mut crawl = Dowser::default()
+			.with_retry(3, Duration::from_millis(1))
+			.with_path("tests/");
+		let mut found: Vec<PathBuf> = crawl.by_ref().collect();
+		found.sort();
+		assert_eq!(found, Dowser::from("tests/").into_sorted_vec());
+		assert!(crawl.failed_reads().is_empty());
 	}
-}
 
+	#[test]
+	fn t_with_time_budget() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
 
+		// Plenty of time.
+		let found = Dowser::default()
+			.with_time_budget(Duration::from_secs(60))
+			.with_path(&test_dir)
+			.into_vec();
+		assert_eq!(found.len(), 9);
 
-/// # Is Singular Path?
-///
-/// Returns true if the type seems to be a singular `Path`/`PathBuf` object.
-/// This is necessary to differentiate them from proper collections
-/// implementing `IntoIterator<AsRef<Path>>`, at least until negative trait
-/// bounds are stabilized.
-fn is_singular_path<T>(raw: T) -> bool {
-	/// # Type to String.
-	fn type_of<T>(_: T) -> &'static str { std::any::type_name::<T>() }
+		// No time at all; only the file roots should make it.
+		let found = Dowser::default()
+			.with_time_budget(Duration::ZERO)
+			.with_paths([test_dir.as_path(), &test_dir.join("assets/file.txt")])
+			.into_vec();
+		assert_eq!(found, [test_dir.join("assets/file.txt")]);
+	}
 
-	let kind = type_of(raw).trim_start_matches('&');
-	kind == "std::path::Path" || kind == "std::path::PathBuf"
-}
+	#[test]
+	fn t_confine_to() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
 
+		// 05/08 leads to 06/08, so nothing should be found.
+		let found = Dowser::default()
+			.confine_to(&[test_dir.join("05")])
+			.with_path(test_dir.join("05"))
+			.into_vec();
+		assert!(found.is_empty());
 
+		// 06/10 leads to 01, and 06/11 to 06.
+		let found = Dowser::default()
+			.confine_to(&[test_dir.join("06")])
+			.with_path(test_dir.join("06"))
+			.into_sorted_vec();
+		assert_eq!(found, [test_dir.join("06/08"), test_dir.join("06/09")]);
 
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use brunch as _;
+		// Roots outside the confinement should be dropped.
+		let found = Dowser::default()
+			.confine_to(&[test_dir.join("06")])
+			.with_paths([test_dir.join("01"), test_dir.join("05")])
+			.into_vec();
+		assert!(found.is_empty());
+
+		// Even if they were queued first.
+		let found = Dowser::default()
+			.with_paths([test_dir.join("01"), test_dir.join("05")])
+			.confine_to(&[test_dir.join("06")])
+			.into_vec();
+		assert!(found.is_empty());
+
+		// With several allowed roots, things should connect.
+		let found = Dowser::default()
+			.confine_to(&[test_dir.join("05"), test_dir.join("06")])
+			.with_path(test_dir.join("05"))
+			.into_vec();
+		assert_eq!(found, [test_dir.join("06/08")]);
+
+		// Bad confinement roots should confine everything.
+		let found = Dowser::default()
+			.confine_to(&[test_dir.join("nope")])
+			.with_path(&test_dir)
+			.into_vec();
+		assert!(found.is_empty());
+	}
 
 	#[test]
-	fn t_new() {
-		let mut abs_dir = std::fs::canonicalize("tests/assets/").unwrap();
-		abs_dir.push("_.txt");
-		let abs_p1 = abs_dir.with_file_name("file.txt");
-		let abs_p2 = abs_dir.with_file_name("is-executable.sh");
-		let abs_perr = abs_dir.with_file_name("foo.bar");
+	fn t_on_permission_denied() {
+		// Test the policy mechanics directly, since root can read anything.
+		for (policy, count, pending) in [
+			(PermissionPolicy::Skip, 0, 1),
+			(PermissionPolicy::Count, 2, 1),
+			(PermissionPolicy::Abort, 2, 0),
+		] {
+			let mut crawl = Dowser::from("./tests").on_permission_denied(policy);
+			crawl.on_denied();
+			crawl.on_read_error(&std::io::Error::from(ErrorKind::PermissionDenied));
+			crawl.on_read_error(&std::io::Error::from(ErrorKind::NotFound));
+			assert_eq!(crawl.permission_denied(), count, "{policy:?}");
+			assert_eq!(crawl.pending_dirs().len(), pending, "{policy:?}");
+		}
 
-		// Builder init.
-		let mut w1: Vec<PathBuf> = Dowser::default()
-			.with_path(PathBuf::from("tests/"))
-			.collect();
-		assert!(! w1.is_empty());
-		assert_eq!(w1.len(), 9);
-		assert!(w1.contains(&abs_p1));
-		assert!(w1.contains(&abs_p2));
-		assert!(! w1.contains(&abs_perr));
+		#[cfg(unix)]
+		{
+			use std::{
+				fs,
+				os::unix::fs::PermissionsExt,
+			};
 
-		// From init.
-		let mut w2: Vec<PathBuf> = Dowser::from("tests/").collect();
-		w1.sort();
-		w2.sort();
-		assert_eq!(w1, w2);
+			// Find the temporary directory.
+			let tmp = std::env::temp_dir();
+			if ! tmp.is_dir() { return; }
+
+			// Set up a locked directory.
+			let tmp = tmp.join("dowser-denied");
+			let locked = tmp.join("locked");
+			let res = fs::create_dir_all(&locked)
+				.and_then(|()| fs::write(tmp.join("a.txt"), b"Hello"))
+				.and_then(|()| fs::write(locked.join("b.txt"), b"World"))
+				.and_then(|()| fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)));
+
+			// This won't work if we're root.
+			if res.is_ok() && fs::read_dir(&locked).is_err() {
+				let mut crawl = Dowser::default()
+					.on_permission_denied(PermissionPolicy::Count)
+					.with_path(&tmp);
+				assert_eq!(crawl.by_ref().count(), 1);
+				assert_eq!(crawl.permission_denied(), 1);
+			}
+
+			let _res = fs::set_permissions(&locked, fs::Permissions::from_mode(0o755));
+			let _res = fs::remove_dir_all(tmp);
+		}
 	}
 
 	#[test]
-	fn t_resolve_path() {
+	fn t_buffered_files() {
 		let test_dir = std::fs::canonicalize("./tests/links")
 			.expect("Missing dowser link directory.");
 
-		// Make sure symlinks are detected.
-		let links = std::fs::read_dir(&test_dir)
-			.expect("Missing dowser link directory.")
-			.filter_map(Result::ok)
-			.filter_map(|e| e.file_type().ok())
-			.filter(std::fs::FileType::is_symlink)
-			.count();
-		assert_eq!(links, 1, "Wrong symlink count!");
+		// Only file roots should be buffered to start with.
+		let mut crawl = Dowser::default()
+			.with_paths([test_dir.join("01"), test_dir.join("05"), test_dir.join("02")]);
+		assert_eq!(crawl.buffered_files(), [test_dir.join("01"), test_dir.join("02")]);
 
-		let raw = vec![
-			test_dir.join("01"),
-			test_dir.join("02"),
-			test_dir.join("03"),
-			test_dir.join("04"),
-			test_dir.join("05"), // Directory.
-			test_dir.join("06"), // Directory.
-			test_dir.join("07"), // Sym to six.
-			test_dir.join("06/08"),
-			test_dir.join("06/09"),
-			test_dir.join("06/10"), // Sym to one.
-			test_dir.join("06/11"), // Sym to seven to six.
-		];
+		// Yielding should drain from the end.
+		assert_eq!(crawl.next(), Some(test_dir.join("02")));
+		assert_eq!(crawl.buffered_files(), [test_dir.join("01")]);
 
-		let mut canon = {
-			let mut tmp: Vec<PathBuf> = raw.iter()
-				.filter_map(|x| std::fs::canonicalize(x).ok())
-				.collect();
-			tmp.sort();
-			tmp.dedup();
-			tmp
-		};
+		// Reading 05 should buffer its (linked) file.
+		assert_eq!(crawl.next(), Some(test_dir.join("01")));
+		assert!(crawl.buffered_files().is_empty());
+		assert_eq!(crawl.next(), Some(test_dir.join("06/08")));
+		assert_eq!(crawl.next(), None);
+	}
 
-		// There should be two fewer entries as two are symlinks.
-		assert_eq!(raw.len(), 11);
-		assert_eq!(canon.len(), 8, "{canon:?}");
-		assert!(! canon.contains(&raw[6]));
-		assert!(! canon.contains(&raw[9]));
-		assert!(! canon.contains(&raw[10]));
+	#[test]
+	fn t_estimate() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
 
-		let trusting = {
-			let mut tmp: Vec<PathBuf> = raw.iter()
-				.filter_map(Entry::from_path)
-				.map(|e| e.path)
-				.collect();
-			tmp.sort();
-			tmp.dedup();
-			tmp
-		};
+		// Flat directories should be spot on.
+		assert_eq!(Dowser::from(test_dir.join("assets")).estimate(), 3);
 
-		assert_eq!(trusting, canon);
+		// As should files.
+		assert_eq!(Dowser::from(test_dir.join("assets/file.txt")).estimate(), 1);
+		assert_eq!(Dowser::default().estimate(), 0);
 
-		// Now let's make sure Dowser does the same thing, albeit just the
-		// files.
-		canon.retain(|p| p.is_file());
+		// Deferred roots should count too.
+		assert_eq!(
+			Dowser::default()
+				.defer_roots()
+				.with_paths([test_dir.join("assets"), test_dir.join("links/01")])
+				.estimate(),
+			4,
+		);
 
-		let mut itered: Vec<PathBuf> = Dowser::from(test_dir.as_path()).collect();
-		itered.sort();
-		assert_eq!(canon, itered);
+		// Nested directories will be fuzzier, but shouldn't be nothing.
+		assert_ne!(Dowser::from(test_dir).estimate(), 0);
+	}
 
-		itered = Dowser::from(test_dir.as_path()).into_vec_filtered(|_| true);
-		itered.sort();
-		assert_eq!(canon, itered);
+	#[test]
+	fn t_pending_dirs() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		let mut crawl = Dowser::from(test_dir.as_path());
+		assert_eq!(crawl.pending_dirs().collect::<Vec<_>>(), [test_dir.as_path()]);
+
+		// Pull the first file, forcing a read of the root, which should
+		// queue up its subdirectories (07 being a link to 06).
+		assert!(crawl.next().is_some(), "Expected a file.");
+		let mut pending: Vec<&Path> = crawl.pending_dirs().collect();
+		pending.sort();
+		assert_eq!(pending, [test_dir.join("05"), test_dir.join("06")]);
+
+		// Once everything's done, nothing should be left.
+		let _res = crawl.by_ref().count();
+		assert_eq!(crawl.pending_dirs().len(), 0);
+	}
+
+	#[test]
+	fn t_with_per_level_dir_limit() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser tests directory.");
+
+		// No subdirectories.
+		assert!(
+			Dowser::default()
+				.with_per_level_dir_limit(0)
+				.with_path(&test_dir)
+				.next()
+				.is_none()
+		);
+
+		// Just the assets.
+		assert_eq!(
+			Dowser::default()
+				.with_per_level_dir_limit(1)
+				.with_path(&test_dir)
+				.into_vec()
+				.len(),
+			3,
+		);
+
+		// Links has two subdirectories, but only 05 should get read. (Its
+		// only entry links to 06/08.)
+		let mut found = Dowser::default()
+			.with_per_level_dir_limit(1)
+			.with_path(test_dir.join("links"))
+			.into_vec();
+		found.sort();
+		assert_eq!(found, [
+			test_dir.join("links/01"),
+			test_dir.join("links/02"),
+			test_dir.join("links/03"),
+			test_dir.join("links/04"),
+			test_dir.join("links/06/08"),
+		]);
+	}
+
+	#[test]
+	fn t_with_max_entries_per_dir() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser tests directory.");
+
+		for prefetch in [false, true] {
+			// Everything fits.
+			let mut crawl = Dowser::default()
+				.with_max_entries_per_dir(7)
+				.with_path(&test_dir);
+			if prefetch { crawl = crawl.with_prefetch(); }
+			assert_eq!(crawl.by_ref().count(), 9);
+			assert!(crawl.truncated_dirs().is_empty());
+
+			// Links has seven entries; nothing else has more than four.
+			let mut crawl = Dowser::default()
+				.with_max_entries_per_dir(4)
+				.with_path(&test_dir);
+			if prefetch { crawl = crawl.with_prefetch(); }
+			let found = crawl.by_ref().count();
+			assert!((3..9).contains(&found), "Unexpected count: {found}");
+			assert_eq!(crawl.truncated_dirs(), [test_dir.join("links")]);
+
+			// Nothing at all.
+			let mut crawl = Dowser::default()
+				.with_max_entries_per_dir(0)
+				.with_path(&test_dir);
+			if prefetch { crawl = crawl.with_prefetch(); }
+			assert!(crawl.next().is_none());
+			assert_eq!(crawl.truncated_dirs(), std::slice::from_ref(&test_dir));
+		}
 	}
 
 	#[test]
@@ -682,4 +7279,59 @@ mod tests {
 			assert!(found.contains(&asset_dir.join("is-executable.sh")));
 		}
 	}
+
+	#[test]
+	fn t_read_paths_from_file_relative() {
+		use std::fs::File;
+		use std::io::Write;
+
+		// Find the temporary directory.
+		let tmp = std::env::temp_dir();
+		if ! tmp.is_dir() { return; }
+
+		let link01 = std::fs::canonicalize("tests/links/01")
+			.expect("Missing dowser links/01");
+
+		// Mock up a manifest with a relative neighbor and an absolute path.
+		let dir = tmp.join("dowser.test.relative");
+		let _res = std::fs::create_dir_all(dir.join("sub"));
+		let res = File::create(dir.join("sub/a.txt"))
+			.and_then(|_| File::create(dir.join("list.txt")))
+			.and_then(|mut file| {
+				let text = format!(
+					"sub\n{}\n",
+					link01.as_os_str().to_str().expect("Link01 cannot be represented as a string."),
+				);
+				file.write_all(text.as_bytes()).and_then(|()| file.flush())
+			});
+
+		// Not all environments will allow that; only proceed with the testing
+		// if it worked.
+		if res.is_ok() && dir.join("list.txt").is_file() {
+			let dir = std::fs::canonicalize(&dir).expect("Missing test dir.");
+
+			let mut crawl = Dowser::default();
+			crawl.read_paths_from_file_relative(dir.join("list.txt"))
+				.expect("Loading text file failed.");
+			let mut found: Vec<PathBuf> = crawl.collect();
+			found.sort();
+
+			// The plain version would look for "sub" in the working directory.
+			let mut crawl = Dowser::default();
+			crawl.read_paths_from_file(dir.join("list.txt"))
+				.expect("Loading text file failed.");
+			let plain: Vec<PathBuf> = crawl.collect();
+
+			// We don't need the files anymore.
+			let _res = std::fs::remove_dir_all(&dir);
+
+			let mut expected = vec![dir.join("sub/a.txt"), link01.clone()];
+			expected.sort();
+			assert_eq!(found, expected);
+			assert_eq!(plain, [link01]);
+		}
+
+		// Missing manifests should fail.
+		assert!(Dowser::default().read_paths_from_file_relative(tmp.join("dowser.nope.txt")).is_err());
+	}
 }
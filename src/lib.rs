@@ -63,6 +63,19 @@ let files2: Vec::<PathBuf> = Dowser::default()
 
 assert_eq!(files1.len(), files2.len());
 ```
+
+
+
+## Optional Features
+
+| Feature | Description |
+| ------- | ----------- |
+| `archives` | Enables `Dowser::with_archives`, for crawling _into_ `.tar` and `.zip` files. |
+| `glob` | Enables `Dowser::with_glob_roots`, for expanding root patterns. |
+| `rayon` | Enables `Dowser::par_iter`, `Dowser::with_thread_pool`, and `IntoParallelIterator` for `Dowser`, for crawling in parallel. |
+| `regex` | Enables `Dowser::regex_exclude`, for excluding paths by pattern. |
+| `tokio` | Enables `Dowser::crawl_stream`, for consuming results asynchronously. |
+| `tracing` | Emits `tracing` spans for each directory read, and events for errors and skipped paths. |
 */
 
 #![forbid(unsafe_code)]
@@ -116,10 +129,35 @@ assert_eq!(files1.len(), files2.len());
 
 #![expect(clippy::redundant_pub_crate, reason = "Unresolvable.")]
 
+#[cfg(feature = "archives")] mod archive;
+mod channel;
 mod entry;
-mod ext;
+pub mod ext;
+mod ignore;
 mod iter;
-
+#[cfg(feature = "rayon")] mod par;
+mod policy;
+mod seen;
+mod source;
+#[cfg(feature = "tokio")] mod stream;
+mod tree;
+mod visit;
+
+#[cfg(feature = "archives")] pub use archive::ArchiveKinds;
+pub use channel::CrawlHandle;
 pub(crate) use entry::Entry;
-pub use ext::Extension;
+pub use ext::{
+	Extension,
+	ExtensionMap,
+	path_is_windows_safe,
+};
 pub use iter::Dowser;
+#[cfg(feature = "rayon")] pub use par::DowserPar;
+pub use policy::{
+	Order,
+	PermissionPolicy,
+};
+pub use seen::SharedSeen;
+pub use source::DirSource;
+pub use tree::DirNode;
+pub use visit::Visitor;
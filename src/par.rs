@@ -0,0 +1,116 @@
+/*!
+# Dowser: Rayon Helpers
+*/
+
+use crate::Dowser;
+use rayon::{
+	iter::{
+		ParallelIterator,
+		plumbing::{
+			bridge_unindexed,
+			Folder,
+			UnindexedConsumer,
+			UnindexedProducer,
+		},
+	},
+	ThreadPool,
+};
+use std::{
+	path::PathBuf,
+	sync::Arc,
+};
+
+
+
+#[derive(Debug)]
+/// # Parallel Crawl.
+///
+/// This is a [`ParallelIterator`] over the files found by a [`Dowser`],
+/// returned by its [`IntoParallelIterator`](rayon::iter::IntoParallelIterator)
+/// implementation.
+///
+/// Unlike bridging the regular iterator, the crawl itself — directory
+/// reads, symlink resolution, filtering — is split across the rayon
+/// workers, which share the pending directories between them as they go.
+/// Deduplication is handled via a [`SharedSeen`](crate::SharedSeen) set,
+/// either the one [provided](Dowser::with_shared_seen) or a fresh one.
+///
+/// The work runs in the [thread pool](Dowser::with_thread_pool), if
+/// specified, or else whatever pool the caller is in (usually the global
+/// one).
+///
+/// Note: each worker keeps its own queue, so crawl-wide limits like
+/// [`PermissionPolicy::Abort`](crate::PermissionPolicy::Abort) only stop
+/// the branch of the crawl they were triggered in. Ordering is arbitrary.
+///
+/// This type requires the `rayon` crate feature.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use dowser::Dowser;
+/// use rayon::iter::{
+///     IntoParallelIterator,
+///     ParallelIterator,
+/// };
+///
+/// let biggest = Dowser::from("/usr/share")
+///     .into_par_iter()
+///     .filter_map(|p| std::fs::metadata(p).ok())
+///     .map(|m| m.len())
+///     .reduce(|| 0, u64::max);
+/// ```
+pub struct DowserPar {
+	/// # Crawler.
+	crawl: Dowser,
+
+	/// # Thread Pool.
+	pool: Option<Arc<ThreadPool>>,
+}
+
+impl DowserPar {
+	/// # New.
+	pub(super) const fn new(crawl: Dowser, pool: Option<Arc<ThreadPool>>) -> Self {
+		Self { crawl, pool }
+	}
+}
+
+impl ParallelIterator for DowserPar {
+	type Item = PathBuf;
+
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		let Self { crawl, pool } = self;
+		let run = move || {
+			let mut crawl = crawl;
+			crawl.par_prepare();
+			bridge_unindexed(CrawlProducer(crawl), consumer)
+		};
+
+		match pool {
+			Some(pool) => pool.install(run),
+			None => run(),
+		}
+	}
+}
+
+
+
+/// # Crawl Producer.
+///
+/// Splitting hands off half the pending work to a new crawler; folding
+/// simply runs the crawl to completion (or until the consumer is full).
+struct CrawlProducer(Dowser);
+
+impl UnindexedProducer for CrawlProducer {
+	type Item = PathBuf;
+
+	fn split(mut self) -> (Self, Option<Self>) {
+		let other = self.0.par_split().map(Self);
+		(self, other)
+	}
+
+	fn fold_with<F>(self, folder: F) -> F
+	where F: Folder<Self::Item> { folder.consume_iter(self.0) }
+}
+
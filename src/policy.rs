@@ -0,0 +1,80 @@
+/*!
+# Dowser: Policies
+*/
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Permission Policy.
+///
+/// This determines how [`Dowser`](crate::Dowser) should react when it comes
+/// across a directory it isn't allowed to read. See
+/// [`Dowser::on_permission_denied`](crate::Dowser::on_permission_denied) for
+/// more details.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use dowser::{Dowser, PermissionPolicy};
+/// use std::path::PathBuf;
+///
+/// let mut crawl = Dowser::default()
+///     .on_permission_denied(PermissionPolicy::Count)
+///     .with_path("/home");
+///
+/// let files: Vec<PathBuf> = crawl.by_ref().collect();
+/// println!("{} directories were unreadable.", crawl.permission_denied());
+/// ```
+pub enum PermissionPolicy {
+	#[default]
+	/// # Skip.
+	///
+	/// Silently skip unreadable directories (the default).
+	Skip,
+
+	/// # Count.
+	///
+	/// Skip unreadable directories, but keep count of them.
+	Count,
+
+	/// # Abort.
+	///
+	/// Stop the crawl at the first unreadable directory.
+	Abort,
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Traversal Order.
+///
+/// This determines the order in which [`Dowser`](crate::Dowser) reads the
+/// directories it finds. See
+/// [`Dowser::with_order`](crate::Dowser::with_order) for more details.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use dowser::{Dowser, Order};
+/// use std::path::PathBuf;
+///
+/// // Shallow files first.
+/// let files: Vec<PathBuf> = Dowser::default()
+///     .with_order(Order::BreadthFirst)
+///     .with_path("/usr/share")
+///     .collect();
+/// ```
+pub enum Order {
+	#[default]
+	/// # Depth-First.
+	///
+	/// Read the most recently found directory next, finishing each subtree
+	/// before moving on to the next (the default).
+	DepthFirst,
+
+	/// # Breadth-First.
+	///
+	/// Read directories in the order they were found, finishing each level
+	/// before moving on to the next.
+	BreadthFirst,
+}
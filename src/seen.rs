@@ -0,0 +1,144 @@
+/*!
+# Dowser: Shared Seen Set
+*/
+
+use dactyl::NoHash;
+use std::{
+	collections::HashSet,
+	sync::{
+		Arc,
+		Mutex,
+		PoisonError,
+	},
+};
+
+
+
+/// # Number of Shards.
+const SHARDS: usize = 16;
+
+/// # Shard.
+type Shard = Mutex<HashSet<u64, NoHash>>;
+
+
+
+#[derive(Debug, Clone, Default)]
+/// # Shared Seen Set.
+///
+/// This is a thread-safe, reference-counted deduplication set that can be
+/// handed to any number of [`Dowser`](crate::Dowser) instances via
+/// [`Dowser::with_shared_seen`](crate::Dowser::with_shared_seen).
+///
+/// Files and directories claimed by any one of them are skipped by all the
+/// others, making it easy to split a large or overlapping job across several
+/// crawlers — or to skip previously-reported files on subsequent runs —
+/// without duplicating work.
+///
+/// Clones are cheap, and point to the same underlying set.
+///
+/// Internally, the set is split into sixteen separately-locked pieces, so
+/// threads claiming different paths rarely wait on one another.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use dowser::{Dowser, SharedSeen};
+/// use std::path::PathBuf;
+///
+/// let seen = SharedSeen::default();
+///
+/// let first: Vec<PathBuf> = Dowser::default()
+///     .with_shared_seen(seen.clone())
+///     .with_path("/usr/share")
+///     .collect();
+///
+/// // This won't yield anything already found by the first.
+/// let second: Vec<PathBuf> = Dowser::default()
+///     .with_shared_seen(seen)
+///     .with_paths(["/usr/share/man", "/usr/local/share/man"])
+///     .collect();
+/// ```
+pub struct SharedSeen(Arc<[Shard; SHARDS]>);
+
+impl SharedSeen {
+	#[must_use]
+	/// # With Capacity.
+	///
+	/// Create a new, empty set with room for at least `capacity` unique
+	/// paths before reallocating.
+	pub fn with_capacity(capacity: usize) -> Self {
+		let capacity = capacity.div_ceil(SHARDS);
+		Self(Arc::new(std::array::from_fn(|_| Mutex::new(
+			HashSet::with_capacity_and_hasher(capacity, NoHash::default())
+		))))
+	}
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of unique files and directories claimed so far.
+	pub fn len(&self) -> usize {
+		self.0.iter()
+			.map(|s| s.lock().unwrap_or_else(PoisonError::into_inner).len())
+			.sum()
+	}
+
+	#[must_use]
+	/// # Is Empty?
+	pub fn is_empty(&self) -> bool {
+		self.0.iter()
+			.all(|s| s.lock().unwrap_or_else(PoisonError::into_inner).is_empty())
+	}
+
+	/// # Clear.
+	///
+	/// Forget everything, allowing previously-claimed paths to be found
+	/// again.
+	pub fn clear(&self) {
+		for s in self.0.iter() {
+			s.lock().unwrap_or_else(PoisonError::into_inner).clear();
+		}
+	}
+
+	/// # Insert.
+	///
+	/// Claim a hash, returning `true` if nobody else already had.
+	pub(super) fn insert(&self, hash: u64) -> bool {
+		self.shard(hash).lock().unwrap_or_else(PoisonError::into_inner).insert(hash)
+	}
+
+	/// # Shard.
+	///
+	/// Return the shard responsible for the hash. The sets themselves bucket
+	/// by the low bits, so the top four are used here.
+	fn shard(&self, hash: u64) -> &Shard {
+		&self.0[(hash >> 60) as usize]
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_shared_seen() {
+		let seen = SharedSeen::with_capacity(16);
+		assert!(seen.is_empty());
+
+		let clone = seen.clone();
+		assert!(seen.insert(1));
+		assert!(! clone.insert(1));
+		assert!(clone.insert(2));
+		assert_eq!(seen.len(), 2);
+
+		// Hashes landing in different shards should add up.
+		assert!(seen.insert(u64::MAX));
+		assert!(! clone.insert(u64::MAX));
+		assert_eq!(clone.len(), 3);
+
+		clone.clear();
+		assert!(seen.is_empty());
+	}
+}
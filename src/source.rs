@@ -0,0 +1,81 @@
+/*!
+# Dowser: Directory Sources
+*/
+
+use std::{
+	io::Result,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+
+
+/// # Directory Source.
+///
+/// By default, [`Dowser`](crate::Dowser) reads directories straight from
+/// the filesystem, but a custom source can be swapped in with
+/// [`Dowser::with_source`](crate::Dowser::with_source) for testing against
+/// in-memory trees, or crawling hierarchies that aren't filesystems at all.
+///
+/// Implementations return the `(path, is_dir)` pairs for each entry inside
+/// `path`. Paths are trusted as-are — no canonicalization or symlink
+/// resolution is performed — so it is up to the source to keep them unique
+/// and consistent. Errors of kind
+/// [`PermissionDenied`](std::io::ErrorKind::PermissionDenied) are subject
+/// to [`Dowser::on_permission_denied`](crate::Dowser::on_permission_denied);
+/// other errors are skipped.
+///
+/// Roots, likewise, should be added with
+/// [`Dowser::with_resolved`](crate::Dowser::with_resolved), since
+/// [`Dowser::with_path`](crate::Dowser::with_path) and kin consult the real
+/// filesystem.
+///
+/// ## Examples
+///
+/// ```
+/// use dowser::{DirSource, Dowser};
+/// use std::{
+///     io::{Error, ErrorKind, Result},
+///     path::{Path, PathBuf},
+/// };
+///
+/// /// # A Tiny Tree.
+/// struct Mock;
+///
+/// impl DirSource for Mock {
+///     fn read_dir(&self, path: &Path) -> Result<Vec<(PathBuf, bool)>> {
+///         match path.to_str() {
+///             Some("/root") => Ok(vec![
+///                 (PathBuf::from("/root/a.txt"), false),
+///                 (PathBuf::from("/root/sub"), true),
+///             ]),
+///             Some("/root/sub") => Ok(vec![
+///                 (PathBuf::from("/root/sub/b.txt"), false),
+///             ]),
+///             _ => Err(Error::from(ErrorKind::NotFound)),
+///         }
+///     }
+/// }
+///
+/// let files = Dowser::default()
+///     .with_source(Mock)
+///     .with_resolved(PathBuf::from("/root"), true)
+///     .into_sorted_vec();
+///
+/// assert_eq!(files, [
+///     PathBuf::from("/root/a.txt"),
+///     PathBuf::from("/root/sub/b.txt"),
+/// ]);
+/// ```
+pub trait DirSource: Send + Sync {
+	/// # Read Directory.
+	///
+	/// Return the `(path, is_dir)` pairs for each entry in the directory.
+	///
+	/// ## Errors
+	///
+	/// Return an error if the directory cannot be read.
+	fn read_dir(&self, path: &Path) -> Result<Vec<(PathBuf, bool)>>;
+}
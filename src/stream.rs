@@ -0,0 +1,98 @@
+/*!
+# Dowser: Async Streams
+*/
+
+use crate::Dowser;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+use tokio_stream::{
+	Stream,
+	wrappers::ReceiverStream,
+};
+
+
+
+/// # Stream Buffer Size.
+///
+/// The maximum number of found paths to hold on to while waiting for the
+/// consumer to catch up.
+const STREAM_BUFFER: usize = 256;
+
+
+
+impl Dowser {
+	/// # Crawl Stream.
+	///
+	/// Crawl the queued paths in the background, returning an async
+	/// [`Stream`] of the files found.
+	///
+	/// Directory reads and canonicalization are blocking operations, so the
+	/// crawl itself is run on tokio's blocking thread pool — the same place
+	/// `tokio::fs` would send them — leaving the executor free to get on with
+	/// other work.
+	///
+	/// Results are passed along through a bounded channel, so the crawl will
+	/// pause whenever the consumer falls behind, and stop altogether if the
+	/// stream is dropped.
+	///
+	/// This method requires the crate feature `tokio`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	/// use tokio_stream::StreamExt;
+	///
+	/// # async fn example() {
+	/// let mut stream = Dowser::default()
+	///     .with_path("/usr/share/man")
+	///     .crawl_stream();
+	///
+	/// while let Some(path) = stream.next().await {
+	///     println!("{}", path.display());
+	/// }
+	/// # }
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if called outside the context of a tokio runtime.
+	pub fn crawl_stream(self) -> impl Stream<Item=PathBuf> + Send + 'static {
+		let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+		tokio::task::spawn_blocking(move || {
+			for p in self {
+				// The receiver is gone; no sense continuing.
+				if tx.blocking_send(p).is_err() { break; }
+			}
+		});
+
+		ReceiverStream::new(rx)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tokio_stream::StreamExt;
+
+	#[test]
+	fn t_crawl_stream() {
+		let rt = tokio::runtime::Builder::new_current_thread()
+			.build()
+			.expect("Failed to build runtime.");
+
+		let mut found: Vec<PathBuf> = rt.block_on(async {
+			Dowser::from("tests/").crawl_stream().collect().await
+		});
+		found.sort();
+		assert_eq!(found, Dowser::from("tests/").into_sorted_vec());
+
+		// Dropping the stream early should be fine too.
+		let found: Vec<PathBuf> = rt.block_on(async {
+			Dowser::from("tests/").crawl_stream().take(2).collect().await
+		});
+		assert_eq!(found.len(), 2);
+	}
+}
@@ -0,0 +1,188 @@
+/*!
+# Dowser: Directory Trees.
+*/
+
+use crate::Dowser;
+use std::{
+	cmp::Reverse,
+	collections::HashMap,
+	path::PathBuf,
+};
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Directory Node.
+///
+/// A directory, along with the files and subdirectories found directly
+/// inside it, as returned by [`Dowser::into_tree`].
+///
+/// All paths are canonical, and the files and subdirectories are sorted.
+pub struct DirNode {
+	/// # Path.
+	pub path: PathBuf,
+
+	/// # Files.
+	pub files: Vec<PathBuf>,
+
+	/// # Subdirectories.
+	pub dirs: Vec<Self>,
+}
+
+impl DirNode {
+	#[must_use]
+	/// # New (Empty).
+	const fn new(path: PathBuf) -> Self {
+		Self { path, files: Vec::new(), dirs: Vec::new() }
+	}
+
+	#[must_use]
+	/// # Total Files.
+	///
+	/// Return the number of files in this node and all of its descendants.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::Dowser;
+	///
+	/// let tree = Dowser::default()
+	///     .with_path("/usr/share/man")
+	///     .into_tree();
+	///
+	/// let total: usize = tree.iter().map(|n| n.total_files()).sum();
+	/// ```
+	pub fn total_files(&self) -> usize {
+		self.files.len() + self.dirs.iter().map(Self::total_files).sum::<usize>()
+	}
+}
+
+impl Dowser {
+	#[must_use]
+	/// # Consume Into Tree.
+	///
+	/// Crawl the paths, returning the results as a hierarchy of
+	/// [`DirNode`]s rather than a flat list, handy for anything that needs to
+	/// render or mirror the directory structure.
+	///
+	/// Because any number of roots can be crawled at once, this returns a
+	/// forest: nodes are nested wherever one is the parent of another, and
+	/// whatever's left over is returned top-level, sorted by path.
+	///
+	/// Files whose parent directories were not crawled — file roots, symlink
+	/// targets, etc. — are grouped under nodes for those parents all the
+	/// same.
+	///
+	/// All the usual filters, exclusions, and symlink settings apply.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use dowser::{DirNode, Dowser};
+	///
+	/// let tree = Dowser::default()
+	///     .with_path("/usr/share/man")
+	///     .into_tree();
+	///
+	/// fn print(node: &DirNode, indent: usize) {
+	///     println!("{:indent$}{}/", "", node.path.display());
+	///     for d in &node.dirs { print(d, indent + 2); }
+	///     for f in &node.files {
+	///         println!("{:indent$}  {}", "", f.display());
+	///     }
+	/// }
+	///
+	/// for node in &tree { print(node, 0); }
+	/// ```
+	pub fn into_tree(self) -> Vec<DirNode> {
+		let (dirs, files) = self.split();
+
+		// Start with an empty node for each directory.
+		let mut nodes: HashMap<PathBuf, DirNode> = dirs.into_iter()
+			.map(|p| (p.clone(), DirNode::new(p)))
+			.collect();
+
+		// Drop the files into their parents.
+		for p in files {
+			if let Some(parent) = p.parent() {
+				nodes.entry(parent.to_path_buf())
+					.or_insert_with(|| DirNode::new(parent.to_path_buf()))
+					.files.push(p);
+			}
+		}
+
+		// Now move each node into its parent, deepest first, so that nodes
+		// are complete by the time they're moved.
+		let mut keys: Vec<PathBuf> = nodes.keys().cloned().collect();
+		keys.sort_unstable_by_key(|k| Reverse(k.components().count()));
+
+		let mut out = Vec::new();
+		for k in keys {
+			let Some(mut node) = nodes.remove(&k) else { continue; };
+			node.files.sort_unstable();
+			node.dirs.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+			match k.parent().and_then(|p| nodes.get_mut(p)) {
+				Some(parent) => { parent.dirs.push(node); },
+				None => { out.push(node); },
+			}
+		}
+
+		out.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+		out
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_into_tree() {
+		let test_dir = std::fs::canonicalize("./tests/links")
+			.expect("Missing dowser link directory.");
+
+		let tree = Dowser::from(test_dir.as_path()).into_tree();
+		assert_eq!(tree, [
+			DirNode {
+				path: test_dir.clone(),
+				files: vec![
+					test_dir.join("01"),
+					test_dir.join("02"),
+					test_dir.join("03"),
+					test_dir.join("04"),
+				],
+				dirs: vec![
+					DirNode::new(test_dir.join("05")),
+					DirNode {
+						path: test_dir.join("06"),
+						files: vec![test_dir.join("06/08"), test_dir.join("06/09")],
+						dirs: Vec::new(),
+					},
+				],
+			},
+		]);
+		assert_eq!(tree[0].total_files(), 6);
+
+		// File roots get nodes for their parents, nested or not.
+		let tree = Dowser::default()
+			.with_path(test_dir.join("06/09"))
+			.with_path(test_dir.join("01"))
+			.with_path("./tests/assets/file.txt")
+			.into_tree();
+		assert_eq!(tree.len(), 2);
+		assert_eq!(tree[1], DirNode {
+			path: test_dir.clone(),
+			files: vec![test_dir.join("01")],
+			dirs: vec![
+				DirNode {
+					path: test_dir.join("06"),
+					files: vec![test_dir.join("06/09")],
+					dirs: Vec::new(),
+				},
+			],
+		});
+	}
+}
@@ -0,0 +1,133 @@
+/*!
+# Dowser: Visitor
+*/
+
+use std::{
+	ops::ControlFlow,
+	path::Path,
+};
+
+
+
+/// # Visitor.
+///
+/// This trait provides an object-oriented alternative to the usual
+/// iterator/closure-based consumption, for use with
+/// [`Dowser::walk`](crate::Dowser::walk).
+///
+/// Implementers must provide [`Visitor::visit_file`], which is called once
+/// for each file found; returning [`ControlFlow::Break`] stops the crawl
+/// early.
+///
+/// [`Visitor::visit_dir`] is optional. It is called for each directory
+/// — roots included — just before it is read, and can return `false` to
+/// skip it (and everything beneath it). By default, everything is crawled.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use dowser::{Dowser, Visitor};
+/// use std::{
+///     ops::ControlFlow,
+///     path::Path,
+/// };
+///
+/// /// # Find the First Ten Files, Skipping .git.
+/// #[derive(Default)]
+/// struct FirstTen(Vec<String>);
+///
+/// impl Visitor for FirstTen {
+///     fn visit_file(&mut self, path: &Path) -> ControlFlow<()> {
+///         self.0.push(path.to_string_lossy().into_owned());
+///         if self.0.len() == 10 { ControlFlow::Break(()) }
+///         else { ControlFlow::Continue(()) }
+///     }
+///
+///     fn visit_dir(&mut self, path: &Path) -> bool {
+///         ! path.ends_with(".git")
+///     }
+/// }
+///
+/// let mut visitor = FirstTen::default();
+/// Dowser::default().with_path("/my/dir").walk(&mut visitor);
+/// ```
+pub trait Visitor {
+	/// # Visit File.
+	///
+	/// Do something with a file. Return [`ControlFlow::Break`] to abort the
+	/// crawl.
+	fn visit_file(&mut self, path: &Path) -> ControlFlow<()>;
+
+	#[inline]
+	/// # Visit Directory.
+	///
+	/// Return `true` to crawl the directory, or `false` to skip it.
+	fn visit_dir(&mut self, _path: &Path) -> bool { true }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Dowser;
+	use std::path::PathBuf;
+
+	/// # Test Visitor.
+	struct Collector {
+		/// # Files.
+		files: Vec<PathBuf>,
+
+		/// # Directories.
+		dirs: Vec<PathBuf>,
+
+		/// # Stop After.
+		max: usize,
+	}
+
+	impl Visitor for Collector {
+		fn visit_file(&mut self, path: &Path) -> ControlFlow<()> {
+			self.files.push(path.to_path_buf());
+			if self.files.len() < self.max { ControlFlow::Continue(()) }
+			else { ControlFlow::Break(()) }
+		}
+
+		fn visit_dir(&mut self, path: &Path) -> bool {
+			self.dirs.push(path.to_path_buf());
+			! path.ends_with("links")
+		}
+	}
+
+	#[test]
+	fn t_walk() {
+		let test_dir = std::fs::canonicalize("./tests")
+			.expect("Missing dowser test directory.");
+
+		// Everything but links.
+		let mut v = Collector { files: Vec::new(), dirs: Vec::new(), max: usize::MAX };
+		Dowser::from(test_dir.as_path()).walk(&mut v);
+		v.files.sort();
+		v.dirs.sort();
+		assert_eq!(v.files, [
+			test_dir.join("assets/file.txt"),
+			test_dir.join("assets/functioning.JPEG"),
+			test_dir.join("assets/is-executable.sh"),
+		]);
+		assert_eq!(v.dirs, [
+			test_dir.clone(),
+			test_dir.join("assets"),
+			test_dir.join("links"),
+		]);
+
+		// Stop early.
+		let mut v = Collector { files: Vec::new(), dirs: Vec::new(), max: 2 };
+		Dowser::from(test_dir.as_path()).walk(&mut v);
+		assert_eq!(v.files.len(), 2);
+
+		// Trait objects should work too.
+		let mut v = Collector { files: Vec::new(), dirs: Vec::new(), max: 1 };
+		let dyn_v: &mut dyn Visitor = &mut v;
+		Dowser::from(test_dir.as_path()).walk(dyn_v);
+		assert_eq!(v.files.len(), 1);
+	}
+}